@@ -1,5 +1,5 @@
 use inflector::Inflector;
-use std::{fs, io::{self, Write}, path, vec, collections::HashMap};
+use std::{fs, io::{self, Write}, path, vec, cell::{Cell, RefCell}, collections::{BTreeSet, HashMap}, convert::TryFrom, rc::Rc};
 use serde::{Deserialize};
 
 mod swc;
@@ -10,7 +10,7 @@ mod wb;
 // for the generator library : use build script to pull in the ts files
 // for the output library: use build script to pull in the js files
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 enum OverrideMode {
     Skip,
@@ -21,20 +21,351 @@ impl Default for OverrideMode {
     fn default() -> Self { OverrideMode::Skip }
 }
 
-#[derive(Default, Deserialize, Debug)]
+/* a name, a glob with * and ? or a regular expression between slashes, e.g., /^to.*JSON$/ */
+#[derive(Deserialize, Debug, Clone)]
+#[serde(try_from = "String")]
+struct Pattern {
+    key: String,
+    regex: Option<regex::Regex>,
+}
+
+impl TryFrom<String> for Pattern {
+    type Error = String;
+
+    fn try_from(key: String) -> Result<Self, Self::Error> {
+        let pattern = if key.len() > 1 && key.starts_with('/') && key.ends_with('/') {
+            Some(format!("^(?:{})$", &key[1..key.len() - 1]))
+        }
+        else if key.contains(['*', '?']) {
+            Some(format!("^{}$", regex::escape(&key).replace("\\*", ".*").replace("\\?", ".")))
+        }
+        else {
+            None
+        };
+        let regex = pattern
+            .map(|pattern| regex::Regex::new(&pattern))
+            .transpose()
+            .map_err(|error| format!("invalid pattern {}: {}", key, error))?;
+        Ok(Pattern { key, regex })
+    }
+}
+
+impl Pattern {
+    fn is_match(&self, name: &str) -> bool {
+        match &self.regex {
+            Some(regex) => regex.is_match(name),
+            None => self.key == name,
+        }
+    }
+
+    /* names take precedence over patterns and longer patterns take precedence over shorter ones */
+    fn precedence(&self, other: &Pattern) -> std::cmp::Ordering {
+        self.regex.is_some()
+            .cmp(&other.regex.is_some())
+            .then(other.key.len().cmp(&self.key.len()))
+            .then(self.key.cmp(&other.key))
+    }
+}
+
+/* overrides by their keys, which are names or patterns */
+#[derive(Default, Deserialize, Debug, Clone)]
+#[serde(try_from = "HashMap<String, T>")]
+struct Overrides<T> {
+    named: HashMap<String, T>,
+    /* the keys that are patterns in the order of their precedence */
+    patterns: Vec<Pattern>,
+    /* the copies of the overrides of the patterns for the names that they matched, the copies
+       share the flags that track whether an override was applied with the original */
+    matched: HashMap<String, T>,
+}
+
+impl<T> TryFrom<HashMap<String, T>> for Overrides<T> {
+    type Error = String;
+
+    fn try_from(named: HashMap<String, T>) -> Result<Self, Self::Error> {
+        let mut patterns = Vec::new();
+        for key in named.keys() {
+            let pattern = Pattern::try_from(key.clone())?;
+            if pattern.regex.is_some() {
+                patterns.push(pattern);
+            }
+        }
+        patterns.sort_unstable_by(Pattern::precedence);
+        Ok(Overrides { named, patterns, matched: HashMap::new() })
+    }
+}
+
+impl<T: Clone> Overrides<T> {
+    /* the override of the pattern with the highest precedence that matches the name */
+    fn pattern(&self, name: &str) -> Option<&T> {
+        self.patterns
+            .iter()
+            .find(|pattern| pattern.is_match(name))
+            .and_then(|pattern| self.named.get(&pattern.key))
+    }
+
+    fn get(&self, name: &str) -> Option<&T> {
+        self.named.get(name).or_else(|| self.pattern(name))
+    }
+
+    /* the overrides of a pattern are copied for each name so that the state of one name, e.g.,
+       the functions that were moved out of a method override, does not affect the others */
+    fn get_mut(&mut self, name: &str) -> Option<&mut T> {
+        if self.named.contains_key(name) {
+            return self.named.get_mut(name);
+        }
+        if !self.matched.contains_key(name) {
+            let pattern_override = self.pattern(name)?.clone();
+            self.matched.insert(name.to_owned(), pattern_override);
+        }
+        self.matched.get_mut(name)
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (&String, &T)> {
+        self.named.iter()
+    }
+}
+
+#[derive(Default, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+enum MethodMode {
+    /* the member is not bound */
+    Skip,
+    /* the member is bound by the given functions instead of the generated ones */
+    Replace,
+    /* the generated functions are bound under a different Rust name */
+    Rename,
+    /* the given functions are bound as extra overloads of the generated ones */
+    Append,
+    /* the generated functions are bound after patching their parameters */
+    #[default]
+    Patch,
+}
+
+/* changes to a parameter of the generated functions, the fields that are not given are kept */
+#[derive(Default, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+struct ParamOverride {
+    #[serde(default, rename = "type")]
+    type_desc: Option<wb::TypeDesc>,
+    #[serde(default)]
+    reference: Option<bool>,
+    #[serde(default)]
+    optional: Option<bool>,
+    #[serde(skip)]
+    used: Rc<Cell<bool>>,
+}
+
+#[derive(Default, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+struct MethodOverride {
+    #[serde(default)]
+    mode: MethodMode,
+    /* the functions for replace and append */
+    #[serde(default)]
+    functions: Vec<wb::FunctionDesc>,
+    /* the Rust name for rename */
+    #[serde(default)]
+    name: Option<String>,
+    /* the patches for the parameters of the generated functions by their names */
+    #[serde(default)]
+    parameters: HashMap<String, ParamOverride>,
+    #[serde(skip)]
+    used: Rc<Cell<bool>>,
+}
+
+impl MethodOverride {
+    /* the functions that bind the member without generating them, the functions are moved
+       out of the override so that overloaded members are only bound once */
+    fn replacement(&mut self) -> Option<Vec<wb::FunctionDesc>> {
+        match self.mode {
+            MethodMode::Skip => Some(Vec::new()),
+            MethodMode::Replace => Some(std::mem::take(&mut self.functions)),
+            _ => None,
+        }
+    }
+
+    /* patches, renames or extends the functions that were generated for the member */
+    fn apply(&mut self, js_name: &str, fn_descs: &mut Vec<wb::FunctionDesc>) {
+        for fn_desc in fn_descs.iter_mut() {
+            for (arg_name, param) in fn_desc.arguments.iter_mut() {
+                if let Some(param_override) = self.parameters.get(arg_name) {
+                    param_override.used.set(true);
+                    if let Some(type_desc) = &param_override.type_desc {
+                        param.type_desc = type_desc.clone();
+                    }
+                    if let Some(reference) = param_override.reference {
+                        param.reference = reference;
+                    }
+                    if let Some(optional) = param_override.optional {
+                        param.optional = optional;
+                    }
+                }
+            }
+        }
+        match self.mode {
+            MethodMode::Rename => match &self.name {
+                Some(name) => for fn_desc in fn_descs.iter_mut() {
+                    rename_function(fn_desc, js_name, name);
+                },
+                None => eprintln!("warning: rename of {} requires a name", js_name),
+            },
+            MethodMode::Append => fn_descs.append(&mut self.functions),
+            _ => {},
+        }
+    }
+}
+
+#[derive(Default, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
 struct ClassOverride {
     mode: OverrideMode,
     #[serde(default)]
-    methods: HashMap<String, Vec<wb::FunctionDesc>>,
+    methods: Overrides<MethodOverride>,
+    /* methods for which a binding is generated for each prefix of the trailing
+       optional arguments, an empty list of names generates the names */
+    #[serde(default)]
+    variants: HashMap<String, Vec<String>>,
+    /* named instances of a generic class and their type arguments */
+    #[serde(default)]
+    instances: HashMap<String, Vec<wb::TypeDesc>>,
+    #[serde(skip)]
+    used: Rc<Cell<bool>>,
+    #[serde(skip)]
+    used_variants: Rc<RefCell<BTreeSet<String>>>,
+}
+
+impl ClassOverride {
+    fn method(&mut self, name: &str) -> Option<&mut MethodOverride> {
+        let method_override = self.methods.get_mut(name)?;
+        method_override.used.set(true);
+        Some(method_override)
+    }
+
+    fn variants(&self, name: &str) -> Option<&Vec<String>> {
+        let variants = self.variants.get(name)?;
+        self.used_variants.borrow_mut().insert(name.to_owned());
+        Some(variants)
+    }
+
+    /* the keys of the overrides that were never applied */
+    fn unused(&self) -> Vec<String> {
+        if !self.used.get() {
+            return vec![String::new()];
+        }
+        let mut unused = Vec::new();
+        for (method_name, method_override) in self.methods.iter() {
+            if !method_override.used.get() {
+                unused.push(format!(".methods.{}", method_name));
+                continue;
+            }
+            for (param_name, param_override) in &method_override.parameters {
+                if !param_override.used.get() {
+                    unused.push(format!(".methods.{}.parameters.{}", method_name, param_name));
+                }
+            }
+        }
+        for variant_name in self.variants.keys() {
+            if !self.used_variants.borrow().contains(variant_name) {
+                unused.push(format!(".variants.{}", variant_name));
+            }
+        }
+        unused
+    }
 }
 
-#[derive(Default, Deserialize, Debug)]
+/* maps the name of a generic class to its named instances and their type arguments */
+type GenericInstances = HashMap<String, Vec<(String, Vec<wb::TypeDesc>)>>;
+
+#[derive(Default, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
 struct ModuleOverride {
     mode: OverrideMode,
+    /* the mode of the classes without overrides, the default mode of the generator if not given */
+    #[serde(default)]
+    default: Option<OverrideMode>,
+    #[serde(default)]
+    classes: Overrides<ClassOverride>,
+    #[serde(skip)]
+    used: Rc<Cell<bool>>,
+}
+
+impl ModuleOverride {
+    /* classes, interfaces, enums, namespaces, functions and values share the overrides of classes */
+    fn class(&self, name: &str) -> Option<&ClassOverride> {
+        let cls_override = self.classes.get(name)?;
+        cls_override.used.set(true);
+        Some(cls_override)
+    }
+
+    fn class_mut(&mut self, name: &str) -> Option<&mut ClassOverride> {
+        let cls_override = self.classes.get_mut(name)?;
+        cls_override.used.set(true);
+        Some(cls_override)
+    }
+
+    /* the keys of the overrides that were never applied */
+    fn unused(&self) -> Vec<String> {
+        if !self.used.get() {
+            return vec![String::new()];
+        }
+        let mut unused = Vec::new();
+        for (cls_name, cls_override) in self.classes.iter() {
+            unused.extend(cls_override
+                .unused()
+                .into_iter()
+                .map(|key| format!("classes.{}{}", cls_name, key)));
+        }
+        unused
+    }
+}
+
+/* maps a TypeScript type to a Rust type, e.g., number to f32 or Vec2 to Vector2 */
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct TypeMapping {
+    /* the name of the TypeScript type or a pattern that matches the names */
+    #[serde(rename = "type")]
+    ts_type: Pattern,
+    rust: String,
+    /* the path of the Rust type that is imported by the bindings, e.g., my_crate::Vec2 */
+    #[serde(default)]
+    import: Option<String>,
+    /* whether arguments of the type are passed by reference */
     #[serde(default)]
-    classes: HashMap<String, ClassOverride>,
+    reference: Option<bool>,
+    /* the modules in which the type is mapped, the type is mapped in all modules if none are given */
+    #[serde(default)]
+    modules: Vec<Pattern>,
+    #[serde(skip)]
+    used: Cell<bool>,
+}
+
+impl TypeMapping {
+    /* the mappings that apply to a module in the order of their precedence, the mappings of a
+       module take precedence over the mappings of all modules */
+    fn module<'a>(type_map: &'a [TypeMapping], module: &str) -> Vec<&'a TypeMapping> {
+        let mut mod_type_map : Vec<_> = type_map
+            .iter()
+            .filter(|mapping| mapping.modules.is_empty() ||
+                              mapping.modules.iter().any(|pattern| pattern.is_match(module)))
+            .collect();
+        mod_type_map.sort_by(|m1, m2| m1.ts_type
+            .precedence(&m2.ts_type)
+            .then(m1.modules.is_empty().cmp(&m2.modules.is_empty())));
+        mod_type_map
+    }
 }
 
+/* an exported interface, the members are kept by their JavaScript names */
+struct InterfaceDecl {
+    members: Vec<(String, Result<Vec<wb::FunctionDesc>, String>)>,
+    extends: Vec<String>,
+    type_params: Vec<String>,
+}
+
+type Interfaces = HashMap<String, InterfaceDecl>;
+
 struct BindingsTargetIterator(vec::Vec<fs::ReadDir>);
 
 impl BindingsTargetIterator {
@@ -96,10 +427,6 @@ fn main() -> std::io::Result<()> {
          pub use self::object_3d::Object3D;
          pub use self::geometry::Geometry;
     */
-    /* TODO investigate the cause of:
-         #[wasm_bindgen]
-         pub fn ();
-   */
     let matches = clap::App::new("threejs-bindgen")
     .version("1.0")
     .author("Michael Allwright <allsey87@gmail.com>")
@@ -111,6 +438,17 @@ fn main() -> std::io::Result<()> {
         .required(true)
         .takes_value(true)
         .value_name("OVERRIDE_DIR"))
+    .arg(clap::Arg::with_name("default-mode")
+        .help("Set the mode of the modules and classes without overrides, skip only generates \
+               the bindings that are listed in the overrides")
+        .long("default-mode")
+        .takes_value(true)
+        .possible_values(&["skip", "override"])
+        .default_value("skip")
+        .value_name("MODE"))
+    .arg(clap::Arg::with_name("strict")
+        .help("Fail if any of the overrides was never applied")
+        .long("strict"))
     .arg(clap::Arg::with_name("bindings-output")
         .help("Set the bindings output directory")
         .long("bindings-output")
@@ -135,12 +473,14 @@ fn main() -> std::io::Result<()> {
             io::Error::new(io::ErrorKind::Other,
                            "Could not parse bindings output argument"))?;
     fs::create_dir_all(output_path)?;
-    let cargo_output_path = output_path.join("Cargo.toml");
-    let cargo_config = include_str!("template.toml");
-    fs::write(cargo_output_path, cargo_config)?;
 
+    let default_mode = match matches.value_of("default-mode") {
+        Some("override") => OverrideMode::Override,
+        _ => OverrideMode::Skip,
+    };
     /* load the overrides */
-    let mut overrides : HashMap<String, ModuleOverride> = HashMap::new();   
+    let mut overrides : HashMap<String, ModuleOverride> = HashMap::new();
+    let mut type_map : Vec<TypeMapping> = Vec::new();
     if let Some(override_dir) = matches.value_of("overrides") {
         for override_entry in fs::read_dir(override_dir)? {
             let override_path = override_entry?.path();
@@ -154,19 +494,82 @@ fn main() -> std::io::Result<()> {
                            "could not convert filestem to string"))?
                     .to_owned();
                 let override_file = fs::File::open(&override_path)?;
+                /* the errors of serde_yaml include the line and column of the invalid entry */
+                let to_io_error = |e: serde_yaml::Error| io::Error::other(format!("{}: {}", override_path.display(), e));
+                /* types.yaml is the type map of all modules rather than the overrides of a module */
+                if override_filestem == "types" {
+                    type_map = serde_yaml::from_reader(override_file).map_err(to_io_error)?;
+                    continue;
+                }
                 let module_override = 
                     serde_yaml::from_reader::<_, ModuleOverride>(override_file)
-                        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+                        .map_err(to_io_error)?;
                 overrides.insert(override_filestem, module_override);
             }
         }
     }
+    let mut overrides = Overrides::try_from(overrides).map_err(io::Error::other)?;
+    /* collect the instances of the generic classes across all modules */
+    let mut generic_instances : GenericInstances = HashMap::new();
+    for (_, mod_overrides) in overrides.iter() {
+        for (cls_name, cls_overrides) in mod_overrides.classes.iter() {
+            let mut instances : Vec<_> = cls_overrides.instances
+                .iter()
+                .map(|(name, type_args)| (name.clone(), type_args.clone()))
+                .collect();
+            if !instances.is_empty() {
+                instances.sort_unstable_by(|i1, i2| i1.0.cmp(&i2.0));
+                generic_instances.insert(cls_name.clone(), instances);
+            }
+        }
+    }
     /* create a vector of paths or an empty vector */
     let paths : Vec<&str> = matches
         .values_of("paths")
         .map_or_else(Vec::new, |paths| paths.collect::<Vec<&str>>());
+    /* collect the interfaces from all modules so that their extends chains can be flattened */
+    let mut interfaces : Interfaces = HashMap::new();
+    for path in &paths {
+        if let Ok(iterator) = BindingsTargetIterator::new(path) {
+            for ts_path in iterator {
+                let ts_path = ts_path?;
+                let (ts_module, ts_comments) = match swc::parse_module(&ts_path) {
+                    Ok(parsed) => parsed,
+                    Err(error) => {
+                        eprintln!("warning: skipping interfaces in {}", error);
+                        continue;
+                    }
+                };
+                for item in &ts_module.body {
+                    if let swc_ecma_ast::ModuleItem::ModuleDecl(
+                        swc_ecma_ast::ModuleDecl::ExportDecl(export)) = item {
+                        if let swc_ecma_ast::Decl::TsInterface(int_declaration) = &export.decl {
+                            interfaces.insert(int_declaration.id.sym.to_string(),
+                                              collect_interface(int_declaration, &ts_comments));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    /* write the helpers for creating typed arrays from slices */
+    let rs_root_dir = output_path.join("src");
+    fs::create_dir_all(&rs_root_dir)?;
+    wb::Writer::new(fs::File::create(rs_root_dir.join("typed_arrays.rs"))?)
+        .write_typed_arrays()?;
+    /* write the helpers for accessing the values of records */
+    wb::Writer::new(fs::File::create(rs_root_dir.join("records.rs"))?)
+        .write_records()?;
     /* process those paths */
+    let mut web_sys_features = BTreeSet::new();
     let mut module_indices = HashMap::new();
+    /* the modules are written once all of them have been processed, so that they only import the
+       symbols that another module has bound */
+    let mut rs_modules = Vec::new();
+    module_indices.insert(rs_root_dir, vec![
+        (String::from("typed_arrays"), vec![String::from("TypedArray")]),
+        (String::from("records"), vec![String::from("Record")]),
+    ]);
     for path in paths {
         if let Ok(iterator) = BindingsTargetIterator::new(path) {
             for ts_path in iterator {
@@ -188,12 +591,18 @@ fn main() -> std::io::Result<()> {
                     .ok_or(io::Error::new(io::ErrorKind::Other,
                            "could not convert typescript file path to a module path"))?
                     .to_owned();
-                /* check if we have any overrides defined for this module */
-                let mod_overrides = overrides.entry(ts_module_path).or_default();
-                /* check if we should skip generating bindings for this module */
+                /* check if we have any overrides defined for this module and if we
+                   should skip generating bindings for it */
+                let mut mod_default = ModuleOverride { mode: default_mode.clone(), ..Default::default() };
+                let mod_overrides = match overrides.get_mut(&ts_module_path) {
+                    Some(mod_overrides) => mod_overrides,
+                    None => &mut mod_default,
+                };
+                mod_overrides.used.set(true);
                 if matches!(mod_overrides.mode, OverrideMode::Skip) {
                     continue;
                 }
+                let cls_mode = mod_overrides.default.clone().unwrap_or_else(|| default_mode.clone());
                 /* generate the AST and get the comments from the typescript */
                 let (ts_module, ts_comments) = swc::parse_module(&ts_path)?;
                 /* get the current directory */
@@ -205,25 +614,55 @@ fn main() -> std::io::Result<()> {
                 let js_path = ts_dir.join(format!("{}.js", ts_module_name.1));
                 /* create the path to the rust binding */
                 /* TODO tidy up this mess with threejs in the path */
+                let ts_module_dir = ts_dir
+                    .strip_prefix("threejs")
+                    .map_err(|e| io::Error::other(e.to_string()))?;
                 let rs_module_dir = output_path
                     .join("src")
-                    .join(ts_dir
-                        .strip_prefix("threejs")
-                        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?);
+                    .join(ts_module_dir);
                 /* create (all parts of) the directory for the rust bindings output */
                 fs::create_dir_all(&rs_module_dir)?;
                 let rs_module_path = rs_module_dir
                     .join(format!("{}.rs", ts_module_name.0));
-                /* store the snake case and camel cases names for the module index */               
-                module_indices
-                    .entry(rs_module_dir)
-                    .or_insert_with(Vec::new)
-                    .push(ts_module_name);
-                /* create the module writer */
-                let mut writer = wb::Writer::new(fs::File::create(rs_module_path)?);
-                let imports = process_imports(&ts_module);
-                writer.write_imports(imports)?;
-                writer.write_line("\nuse wasm_bindgen::prelude::*;\n")?;
+                /* the types exported by the module for the module index */
+                let mut mod_symbols = Vec::new();
+                let mut imports = process_imports(&ts_module, ts_module_dir);
+                let rs_module = crate_module(&ts_path)
+                    .ok_or(io::Error::other("could not convert typescript file path to a crate module"))?;
+                /* import the instances of generic classes instead of the generic classes */
+                for symbols in imports.values_mut() {
+                    *symbols = symbols
+                        .drain(..)
+                        .flat_map(|symbol| match generic_instances.get(&symbol) {
+                            Some(instances) => instances.iter().map(|i| i.0.clone()).collect(),
+                            None => vec![symbol],
+                        })
+                        /* built-in types, e.g., the TypedArray alias, are bound by js_sys and
+                           DOM types, e.g., AudioContext, are bound by web_sys */
+                        .filter(|symbol| wb::TypeDesc::builtin(symbol).is_none() &&
+                                         wb::TypeDesc::dom(symbol).is_none())
+                        .collect();
+                }
+                /* the javascript module, parsed on demand for its constants and exports */
+                let mut js_module = None;
+                /* the declarations of the module are written together once they have been processed */
+                let mod_attributes = vec![
+                    (String::from("module"), js_path.to_str().map(str::to_owned))
+                ];
+                let mut mod_desc = wb::ModuleDesc::new(mod_attributes);
+                /* values with the type of an enum are bound as the variants of that enum */
+                let mod_enums : Vec<String> = ts_module.body
+                    .iter()
+                    .filter_map(|item| match item {
+                        swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::ExportDecl(export)) =>
+                            match &export.decl {
+                                swc_ecma_ast::Decl::TsEnum(enum_declaration) =>
+                                    Some(enum_declaration.id.sym.to_string()),
+                                _ => None,
+                            },
+                        _ => None,
+                    })
+                    .collect();
                 /* process the components of the typescript module's body */
                 for item in &ts_module.body {
                     if let swc_ecma_ast::ModuleItem::ModuleDecl(declaration) = item {
@@ -236,23 +675,209 @@ fn main() -> std::io::Result<()> {
                                     continue;
                             }
                             if let swc_ecma_ast::Decl::Class(cls_declaration) = &export.decl {
-                                /* get the overrides for this class */
-                                let cls_overrides = mod_overrides.classes
-                                    .entry(cls_declaration.ident.sym.to_string())
-                                    .or_default();
-                                /* skip if the override mode indicates this */
+                                /* get the overrides for this class and skip the class if
+                                   the override mode indicates this */
+                                let mut cls_default = ClassOverride { mode: cls_mode.clone(), ..Default::default() };
+                                let cls_overrides = match mod_overrides.class_mut(&cls_declaration.ident.sym) {
+                                    Some(cls_overrides) => cls_overrides,
+                                    None => &mut cls_default,
+                                };
                                 if matches!(cls_overrides.mode, OverrideMode::Skip) {
                                     continue;
                                 }
                                 let mod_class = process_class(cls_declaration, cls_overrides,  &ts_comments);
-                                /* NOTE there is a one class per module assumption built-in here */
-                                let mod_attributes = vec![
-                                    (String::from("module"), js_path.to_str().and_then(|s| Some(s.to_owned())))
-                                ];
-                                writer.write_module(wb::ModuleDesc::new(mod_attributes, mod_class))?;
+                                for mod_class in process_generics(mod_class, &generic_instances) {
+                                    mod_symbols.push(mod_class.name.clone());
+                                    mod_desc.classes.push(mod_class);
+                                }
+                            }
+                            else if let swc_ecma_ast::Decl::TsEnum(enum_declaration) = &export.decl {
+                                /* enums are bound unless the overrides skip them */
+                                let enum_name = enum_declaration.id.sym.to_string();
+                                if matches!(mod_overrides.class(&enum_name),
+                                            Some(ClassOverride { mode: OverrideMode::Skip, .. })) {
+                                    continue;
+                                }
+                                let mod_enum = if enum_declaration.members.is_empty() {
+                                    let js_constants = js_module
+                                        .get_or_insert_with(|| parse_js_module(&js_path))
+                                        .as_ref()
+                                        .map(process_constants)
+                                        .unwrap_or_default();
+                                    process_constant_enum(&enum_name, &ts_module, &js_constants)
+                                }
+                                else {
+                                    process_enum(enum_declaration)
+                                };
+                                if mod_enum.variants.is_empty() {
+                                    eprintln!("warning: skipping {}: no variants can be bound", enum_name);
+                                    continue;
+                                }
+                                mod_symbols.push(enum_name);
+                                mod_desc.enums.push(mod_enum);
+                            }
+                            else if let swc_ecma_ast::Decl::TsInterface(int_declaration) = &export.decl {
+                                /* interfaces are bound unless the overrides skip them */
+                                let int_name = int_declaration.id.sym.to_string();
+                                let int_overrides = mod_overrides.class_mut(&int_name);
+                                if matches!(int_overrides, Some(ClassOverride { mode: OverrideMode::Skip, .. })) {
+                                    continue;
+                                }
+                                let mod_interface = process_interface(&int_name, &interfaces, int_overrides);
+                                for mod_interface in process_generics(mod_interface, &generic_instances) {
+                                    mod_symbols.push(mod_interface.name.clone());
+                                    mod_desc.interfaces.push(mod_interface);
+                                }
+                            }
+                            else if let swc_ecma_ast::Decl::TsTypeAlias(alias_declaration) = &export.decl {
+                                /* aliases of unions of string literals are bound as enums, other aliases
+                                   are resolved where they are used, e.g., TypedArray */
+                                let alias_name = alias_declaration.id.sym.to_string();
+                                if matches!(mod_overrides.class(&alias_name),
+                                            Some(ClassOverride { mode: OverrideMode::Skip, .. })) {
+                                    continue;
+                                }
+                                if let Ok(wb::TypeDesc::Literals(values)) = process_type(&alias_declaration.type_ann) {
+                                    mod_symbols.push(alias_name.clone());
+                                    mod_desc.string_enums.push(process_string_enum(alias_name, values));
+                                }
                             }
+                            else if let swc_ecma_ast::Decl::TsModule(ns_declaration) = &export.decl {
+                                /* namespaces are bound unless the overrides skip them */
+                                let ns_name = match &ns_declaration.id {
+                                    swc_ecma_ast::TsModuleName::Ident(ident) => ident.sym.to_string(),
+                                    swc_ecma_ast::TsModuleName::Str(string) => string.value.to_string(),
+                                };
+                                let ns_overrides = mod_overrides.class_mut(&ns_name);
+                                if matches!(ns_overrides, Some(ClassOverride { mode: OverrideMode::Skip, .. })) {
+                                    continue;
+                                }
+                                /* a namespace is either exported as an object or its members are exported directly */
+                                let js_exports = js_module
+                                    .get_or_insert_with(|| parse_js_module(&js_path))
+                                    .as_ref()
+                                    .map(process_exports);
+                                let js_namespace = match &js_exports {
+                                    Some(js_exports) if !js_exports.contains(&ns_name) => None,
+                                    _ => Some(ns_name.as_str()),
+                                };
+                                let mut mod_namespace = process_namespace(ns_declaration,
+                                                                          &ns_name,
+                                                                          js_namespace,
+                                                                          js_exports.as_deref(),
+                                                                          ns_overrides,
+                                                                          &ts_comments);
+                                if mod_namespace.functions.is_empty() {
+                                    eprintln!("warning: skipping {}: no members can be bound", ns_name);
+                                    continue;
+                                }
+                                /* a namespace that names its file is bound by the module of the file */
+                                let ns_module = escape_keyword(ns_name.to_snake_case());
+                                if ns_module != ts_module_name.0 {
+                                    mod_symbols.push(ns_module.clone());
+                                    mod_namespace.module = Some(ns_module);
+                                }
+                                mod_desc.namespaces.push(mod_namespace);
+                            }
+                            else if let swc_ecma_ast::Decl::Fn(fn_declaration) = &export.decl {
+                                /* functions are bound unless the overrides skip them */
+                                let fn_name = fn_declaration.ident.sym.to_string();
+                                if matches!(mod_overrides.class(&fn_name),
+                                            Some(ClassOverride { mode: OverrideMode::Skip, .. })) {
+                                    continue;
+                                }
+                                let js_exports = js_module
+                                    .get_or_insert_with(|| parse_js_module(&js_path))
+                                    .as_ref()
+                                    .map(process_exports);
+                                if !js_exports.is_none_or(|js_exports| js_exports.contains(&fn_name)) {
+                                    eprintln!("warning: skipping {}: not exported by the javascript module", fn_name);
+                                    continue;
+                                }
+                                match process_fn_declaration(fn_declaration, Vec::new()) {
+                                    Ok(fn_desc) => mod_desc.functions.push(fn_desc),
+                                    Err(error) => eprintln!("warning: skipping {}: {}", fn_name, error),
+                                }
+                            }
+                            else if let swc_ecma_ast::Decl::Var(var_declaration) = &export.decl {
+                                for declarator in &var_declaration.decls {
+                                    let (ident, type_ann) = match &declarator.name {
+                                        swc_ecma_ast::Pat::Ident(ident) => match &ident.type_ann {
+                                            Some(type_ann) => (ident, &type_ann.type_ann),
+                                            None => continue,
+                                        },
+                                        _ => continue,
+                                    };
+                                    let var_name = ident.sym.to_string();
+                                    let enum_type = match process_type(type_ann) {
+                                        Ok(wb::TypeDesc::Class(type_name)) => mod_enums.contains(&type_name),
+                                        _ => false,
+                                    };
+                                    /* values are bound unless the overrides skip them */
+                                    if enum_type || matches!(mod_overrides.class(&var_name),
+                                                             Some(ClassOverride { mode: OverrideMode::Skip, .. })) {
+                                        continue;
+                                    }
+                                    let js_exports = js_module
+                                        .get_or_insert_with(|| parse_js_module(&js_path))
+                                        .as_ref()
+                                        .map(process_exports);
+                                    if !js_exports.is_none_or(|js_exports| js_exports.contains(&var_name)) {
+                                        eprintln!("warning: skipping {}: not exported by the javascript module", var_name);
+                                        continue;
+                                    }
+                                    match process_static(&var_name, type_ann) {
+                                        Ok(static_desc) => mod_desc.statics.push(static_desc),
+                                        Err(error) => eprintln!("warning: skipping {}: {}", var_name, error),
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                process_overloads(&mut mod_desc.functions);
+                mod_symbols.extend(process_type_literals(&mut mod_desc, &interfaces));
+                process_type_map(&mut mod_desc, &TypeMapping::module(&type_map, &ts_module_path), &mut imports);
+                mod_symbols.extend(mod_desc.functions.iter().map(|fn_desc| fn_desc.name.clone()));
+                mod_symbols.extend(mod_desc.statics.iter().map(|static_desc| static_desc.name.clone()));
+                /* import the DOM types from web_sys and enable their features */
+                let mut dom_types = Vec::new();
+                mod_desc.visit_types_mut(&mut |type_desc| {
+                    if let Some(dom_type) = type_desc.dom_feature() {
+                        if !dom_types.contains(&dom_type) {
+                            dom_types.push(dom_type);
                         }
                     }
+                });
+                web_sys_features.extend(dom_types.iter().copied());
+                if !dom_types.is_empty() {
+                    imports.insert(String::from("web_sys"),
+                                   dom_types.into_iter().map(str::to_owned).collect());
+                }
+                rs_modules.push((rs_module_path, rs_module, imports, mod_desc, mod_symbols.clone()));
+                /* store the module name and its types for the module index */
+                module_indices
+                    .entry(rs_module_dir)
+                    .or_insert_with(Vec::new)
+                    .push((ts_module_name.0, mod_symbols));
+            }
+            /* declare the directories of nested modules in the module indices of their parents,
+               e.g., extras/mod.rs declares extras/core */
+            let module_dirs : Vec<path::PathBuf> = module_indices.keys().cloned().collect();
+            for module_dir in module_dirs {
+                let mut module_dir = module_dir.as_path();
+                while let (Some(parent_dir), Some(dir_name)) = (module_dir.parent(), module_dir.file_name()) {
+                    if !parent_dir.starts_with(output_path.join("src")) {
+                        break;
+                    }
+                    let dir_name = dir_name.to_string_lossy().into_owned();
+                    let module_index_entries = module_indices
+                        .entry(parent_dir.to_owned())
+                        .or_insert_with(Vec::new);
+                    if !module_index_entries.iter().any(|entry| entry.0 == dir_name) {
+                        module_index_entries.push((dir_name, Vec::new()));
+                    }
+                    module_dir = parent_dir;
                 }
             }
             /* create module indices */
@@ -269,14 +894,71 @@ fn main() -> std::io::Result<()> {
                 }
                 writeln!(&mut module_index_buffer, "")?;
                 for module_index_entry in module_index_entries.iter() {
-                    writeln!(&mut module_index_buffer,
-                             "pub use self::{}::{};",
-                             module_index_entry.0,
-                             module_index_entry.1)?;
+                    match &module_index_entry.1[..] {
+                        [] => {},
+                        [symbol] => {
+                            writeln!(&mut module_index_buffer,
+                                     "pub use self::{}::{};",
+                                     module_index_entry.0,
+                                     symbol)?;
+                        },
+                        symbols => {
+                            writeln!(&mut module_index_buffer,
+                                     "pub use self::{}::{{{}}};",
+                                     module_index_entry.0,
+                                     symbols.join(", "))?;
+                        }
+                    }
                 }
             }
         }
     }
+    /* the modules that bind each symbol */
+    let mut bound_symbols : HashMap<String, String> = HashMap::new();
+    for (_, rs_module, _, _, mod_symbols) in &rs_modules {
+        for symbol in mod_symbols {
+            bound_symbols.entry(symbol.clone()).or_insert_with(|| rs_module.clone());
+        }
+    }
+    for (rs_module_path, rs_module, imports, mut mod_desc, mod_symbols) in rs_modules {
+        let imports = process_bound_imports(&mut mod_desc, &rs_module, imports, &mod_symbols, &bound_symbols);
+        let mut writer = wb::Writer::new(fs::File::create(rs_module_path)?);
+        writer.write_imports(imports)?;
+        writer.write_line("\nuse wasm_bindgen::prelude::*;\n")?;
+        if !mod_desc.is_empty() {
+            writer.write_module(mod_desc)?;
+        }
+    }
+    /* write the manifest with the web-sys features that are required by the bindings */
+    let mut cargo_config = String::from(include_str!("template.toml"));
+    cargo_config.push_str("features = [\n");
+    for web_sys_feature in web_sys_features {
+        cargo_config.push_str(&format!("    \"{}\",\n", web_sys_feature));
+    }
+    cargo_config.push_str("]\n");
+    fs::write(output_path.join("Cargo.toml"), cargo_config)?;
+    /* report the overrides that did not match a module, class, member or parameter */
+    let mut unused_overrides = Vec::new();
+    for (mod_name, mod_overrides) in overrides.iter() {
+        for key in mod_overrides.unused() {
+            unused_overrides.push(match key.is_empty() {
+                true => format!("{}.yaml", mod_name),
+                false => format!("{}.yaml: {}", mod_name, key),
+            });
+        }
+    }
+    for (index, mapping) in type_map.iter().enumerate() {
+        if !mapping.used.get() {
+            unused_overrides.push(format!("types.yaml: {} ({})", index, mapping.ts_type.key));
+        }
+    }
+    unused_overrides.sort_unstable();
+    for unused_override in &unused_overrides {
+        eprintln!("warning: override {} was never applied", unused_override);
+    }
+    if matches.is_present("strict") && !unused_overrides.is_empty() {
+        return Err(io::Error::other(format!("{} overrides were never applied", unused_overrides.len())));
+    }
     Ok(())
 }
 
@@ -294,12 +976,57 @@ fn process_type(ts_type: &swc_ecma_ast::TsType)
                     if let Some(params) = &ts_type_ref.type_params {
                         let param = &params.params[0];
                         let inner = process_type(&*param)?;
-                        Ok(wb::TypeDesc::Array(Box::new(inner)))
+                        /* numbers can be passed in any typed array without conversion */
+                        if inner == wb::TypeDesc::Number {
+                            Ok(wb::TypeDesc::Builtin(String::from("ArrayLike")))
+                        }
+                        else {
+                            Ok(wb::TypeDesc::Array(Box::new(inner)))
+                        }
                     }
                     else {
                         Err("ArrayLike without type annotations".to_owned())
                     }
                 }
+                /* Array<T> is the generic form of T[] */
+                else if &*ident.sym == "Array" {
+                    let inner = match &ts_type_ref.type_params {
+                        Some(params) if params.params.len() == 1 => process_type(&params.params[0])?,
+                        _ => wb::TypeDesc::Any,
+                    };
+                    Ok(wb::TypeDesc::Array(Box::new(inner)))
+                }
+                /* Record<string, T> is the generic form of { [key: string]: T } */
+                else if &*ident.sym == "Record" {
+                    let value_type = match &ts_type_ref.type_params {
+                        Some(params) if params.params.len() == 2 => process_type(&params.params[1])?,
+                        _ => wb::TypeDesc::Any,
+                    };
+                    Ok(wb::TypeDesc::Record(Box::new(value_type)))
+                }
+                else if &*ident.sym == "Promise" {
+                    let resolved = match &ts_type_ref.type_params {
+                        Some(params) if params.params.len() == 1 => process_type(&params.params[0])?,
+                        _ => wb::TypeDesc::Any,
+                    };
+                    Ok(wb::TypeDesc::Promise(Box::new(resolved)))
+                }
+                else if let Some(builtin) = wb::TypeDesc::builtin(&ident.sym) {
+                    Ok(builtin)
+                }
+                else if let Some(dom) = wb::TypeDesc::dom(&ident.sym) {
+                    Ok(dom)
+                }
+                else if let Some(gl) = wb::TypeDesc::gl(&ident.sym) {
+                    Ok(gl)
+                }
+                else if let Some(params) = &ts_type_ref.type_params {
+                    let type_args = params.params
+                        .iter()
+                        .map(|param| process_type(param))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Ok(wb::TypeDesc::Generic(ident.sym.to_string(), type_args))
+                }
                 else {
                     Ok(wb::TypeDesc::Class(ident.sym.to_string()))
                 }
@@ -339,10 +1066,34 @@ fn process_type(ts_type: &swc_ecma_ast::TsType)
                     for ts_type in &ts_union_type.types {
                         ts_types.push(process_type(&**ts_type)?);
                     }
+                    /* a union of string literals, which may be null, is bound as an enum */
+                    let literals : Vec<String> = ts_types
+                        .iter()
+                        .filter_map(|ts_type| match ts_type {
+                            wb::TypeDesc::Literal(literal) => Some(literal.clone()),
+                            _ => None,
+                        })
+                        .collect();
+                    let only_literals = ts_types
+                        .iter()
+                        .all(|ts_type| matches!(ts_type, wb::TypeDesc::Literal(_) |
+                                                         wb::TypeDesc::Null |
+                                                         wb::TypeDesc::Undefined));
+                    if literals.len() > 1 && only_literals {
+                        ts_types.retain(|ts_type| !matches!(ts_type, wb::TypeDesc::Literal(_)));
+                        if ts_types.is_empty() {
+                            return Ok(wb::TypeDesc::Literals(literals));
+                        }
+                        ts_types.insert(0, wb::TypeDesc::Literals(literals));
+                    }
                     Ok(wb::TypeDesc::Union(ts_types))
                 },
                 swc_ecma_ast::TsUnionOrIntersectionType::TsIntersectionType(ts_intersection_type) => {
-                    Err(format!("cannot process TsIntersectionType::{{{:?}}}", ts_intersection_type.types))
+                    let ts_types = ts_intersection_type.types
+                        .iter()
+                        .map(|ts_type| process_type(ts_type))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Ok(wb::TypeDesc::Intersection(ts_types))
                 }
             }
         },
@@ -350,119 +1101,639 @@ fn process_type(ts_type: &swc_ecma_ast::TsType)
             if let swc_ecma_ast::TsFnOrConstructorType::TsFnType(function) = fn_or_constructor {
                 let fn_parameters = function.params
                     .iter()
-                    .try_fold(Vec::with_capacity(function.params.len()), |mut vec, param| {
-                        if let swc_ecma_ast::TsFnParam::Ident(ident) = param {
-                            if let Some(type_ann) = &ident.type_ann {
-                                let fn_param_type_desc = process_type(&type_ann.type_ann)?;
-                                vec.push((ident.sym.to_snake_case(), fn_param_type_desc));
-                                Ok(vec)
-                            }
-                            else {
-                                Err(format!("TsFnOrConstructorType: {}", ident.sym))
-                            }
-                        }
-                        else {
-                            Err(format!("TsFnOrConstructorType"))
-                        }
-                    })?;
+                    .enumerate()
+                    .map(|(index, param)| process_callback_parameter(index, param))
+                    .collect::<Result<Vec<_>, _>>()?;
                 let fn_return_type = Box::new(process_type(&function.type_ann.type_ann)?);
-                Ok(wb::TypeDesc::Function(fn_parameters, Some(fn_return_type)))
+                let mut fn_type_desc = wb::TypeDesc::Function(fn_parameters, Some(fn_return_type));
+                /* the type parameters of a callback are bound as their constraints */
+                let substitutions = process_type_params(function.type_params.as_ref())?;
+                fn_type_desc.visit_mut(&mut |type_desc| substitute_type_param(type_desc, &substitutions));
+                Ok(fn_type_desc)
             }
             else {
                 Err(format!("cannot process TsType::{:?}", ts_type))
             }
         },
+        swc_ecma_ast::TsType::TsTupleType(ts_tuple_type) => {
+            let elem_types = ts_tuple_type.elem_types
+                .iter()
+                .map(|elem_type| process_type(elem_type))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(wb::TypeDesc::Tuple(elem_types))
+        },
+        swc_ecma_ast::TsType::TsLitType(ts_lit_type) => {
+            match &ts_lit_type.lit {
+                swc_ecma_ast::TsLit::Str(literal) => Ok(wb::TypeDesc::Literal(literal.value.to_string())),
+                swc_ecma_ast::TsLit::Number(_) => Ok(wb::TypeDesc::Number),
+                swc_ecma_ast::TsLit::Bool(_) => Ok(wb::TypeDesc::Boolean),
+                swc_ecma_ast::TsLit::Tpl(_) => Ok(wb::TypeDesc::String),
+            }
+        },
+        swc_ecma_ast::TsType::TsTypeLit(ts_type_lit) => process_type_literal(ts_type_lit),
         _ => {
             Ok(wb::TypeDesc::Unimplemented)
         }
     }
 }
 
-fn process_parameter(parameter: &swc_ecma_ast::Param)
-    -> Result<(String, wb::ParamDesc), String> {
-    if let swc_ecma_ast::Pat::Ident(identifier) = &parameter.pat {
-        if let Some(ts_type) = &identifier.type_ann {
-            let name = identifier.sym.to_snake_case();
-            let type_desc = process_type(&ts_type.type_ann)?;
-            Ok((name, wb::ParamDesc::new(type_desc, false, identifier.optional)))
-        }
-        else {
-            Err("cannot process parameter without type annotation".to_owned())
+/* object literals with properties are bound as interfaces and those with only an index
+   signature are bound as records */
+fn process_type_literal(ts_type_lit: &swc_ecma_ast::TsTypeLit)
+    -> Result<wb::TypeDesc, String> {
+    let mut properties = Vec::new();
+    let mut index_type = None;
+    for member in &ts_type_lit.members {
+        match member {
+            swc_ecma_ast::TsTypeElement::TsPropertySignature(property) if !property.computed => {
+                let name = match &*property.key {
+                    swc_ecma_ast::Expr::Ident(ident) => ident.sym.to_string(),
+                    swc_ecma_ast::Expr::Lit(swc_ecma_ast::Lit::Str(literal)) => literal.value.to_string(),
+                    _ => return Err(String::from("cannot process property key of type literal")),
+                };
+                let type_desc = match &property.type_ann {
+                    Some(type_ann) => process_type(&type_ann.type_ann)?,
+                    None => wb::TypeDesc::Any,
+                };
+                /* optional properties may be undefined */
+                let type_desc = match (type_desc, property.optional) {
+                    (wb::TypeDesc::Union(mut types), true) => {
+                        types.push(wb::TypeDesc::Undefined);
+                        wb::TypeDesc::Union(types)
+                    },
+                    (type_desc, true) => wb::TypeDesc::Union(vec![type_desc, wb::TypeDesc::Undefined]),
+                    (type_desc, false) => type_desc,
+                };
+                properties.push((name, type_desc));
+            },
+            swc_ecma_ast::TsTypeElement::TsIndexSignature(index_signature) => {
+                index_type = match &index_signature.type_ann {
+                    Some(type_ann) => Some(process_type(&type_ann.type_ann)?),
+                    None => Some(wb::TypeDesc::Any),
+                };
+            },
+            _ => return Err(String::from("cannot process methods of type literal")),
         }
     }
-    else {
-        Err(format!("cannot process parameter without identifier {:?}", parameter))
+    match index_type {
+        Some(index_type) if properties.is_empty() => Ok(wb::TypeDesc::Record(Box::new(index_type))),
+        _ => Ok(wb::TypeDesc::Object(properties)),
     }
 }
 
-fn process_function(name: &str,
-                    attributes: Vec<(String, Option<String>)>,
-                    parameters: &[&swc_ecma_ast::Param],
-                    return_type: &Option<&swc_ecma_ast::TsType>)
-    -> Result<wb::FunctionDesc, String> {
-    /* process the parameters */
-    let fn_arguments = parameters
+/* names the type literals after the members that use them, the object literals are bound as
+   interfaces, the unions of string literals as enums and the intersections as extern types */
+fn process_type_literals(module: &mut wb::ModuleDesc, interfaces: &Interfaces) -> Vec<String> {
+    /* the names of the declared types are taken so that the literals that would have them are numbered */
+    let mut literals : Vec<_> = module.classes
         .iter()
-        .map(|p| process_parameter(p))
-        .collect::<Result<Vec<_>, _>>()?;
-    /* process return type */
-    if let Some(return_type) = return_type {
-        let mut return_type = process_type(&return_type)?;
-        // TODO do not filter out TsVoid here
-        if matches!(return_type, wb::TypeDesc::Void) {
-            Ok(wb::FunctionDesc::new(attributes,
-                name.to_owned(),
-                fn_arguments,
-                None))
+        .chain(module.interfaces.iter())
+        .map(|class| class.name.clone())
+        .chain(module.enums.iter().map(|enumeration| enumeration.name.clone()))
+        .chain(module.string_enums.iter().map(|enumeration| enumeration.name.clone()))
+        .map(|name| (name, wb::TypeDesc::Unimplemented))
+        .collect();
+    let declared = literals.len();
+    for class in module.classes.iter_mut().chain(module.interfaces.iter_mut()) {
+        for method in class.methods.iter_mut() {
+            name_type_literals(&class.name, method, &mut literals);
         }
-        else {
-            let mut optional = false;
-            /* handle special option case */
-            if let wb::TypeDesc::Union(union) = &mut return_type {
-                match &union[..] {
-                    [_, wb::TypeDesc::Null] | [_, wb::TypeDesc::Undefined] => {
-                        return_type = union.remove(0);
-                        optional = true;
-                    },
-                    _ => {}
+    }
+    for function in module.functions.iter_mut() {
+        name_type_literals("", function, &mut literals);
+    }
+    for namespace in module.namespaces.iter_mut() {
+        let namespace_name = namespace.name.to_pascal_case();
+        for function in namespace.functions.iter_mut() {
+            name_type_literals(&namespace_name, function, &mut literals);
+        }
+    }
+    /* the properties of the interfaces may contain more type literals */
+    let mut index = declared;
+    while index < literals.len() {
+        let (name, literal) = literals[index].clone();
+        match literal {
+            wb::TypeDesc::Object(properties) => {
+                let int_methods = process_literal_properties(&name, properties, &mut literals);
+                let int_attributes = vec![(String::from("extends"), Some(String::from("js_sys::Object")))];
+                module.interfaces.push(wb::ClassDesc::new(name, int_attributes, int_methods));
+            },
+            /* the properties of the object literals are merged while the other types are extended */
+            wb::TypeDesc::Intersection(types) => {
+                let mut properties = Vec::new();
+                let mut int_attributes = Vec::new();
+                let mut constructible = true;
+                for type_desc in types {
+                    match type_desc {
+                        wb::TypeDesc::Object(mut type_properties) => properties.append(&mut type_properties),
+                        wb::TypeDesc::Class(class_name) => {
+                            constructible &= interfaces.contains_key(&class_name);
+                            int_attributes.push((String::from("extends"), Some(class_name)));
+                        },
+                        _ => {},
+                    }
+                }
+                int_attributes.push((String::from("extends"), Some(String::from("js_sys::Object"))));
+                let int_methods = process_literal_properties(&name, properties, &mut literals);
+                let int_desc = wb::ClassDesc::new(name, int_attributes, int_methods);
+                /* only intersections of interfaces can be created from an object */
+                match constructible {
+                    true => module.interfaces.push(int_desc),
+                    false => module.intersections.push(int_desc),
                 }
+            },
+            wb::TypeDesc::Literals(values) => module.string_enums.push(process_string_enum(name, values)),
+            _ => {},
+        }
+        index += 1;
+    }
+    literals
+        .into_iter()
+        .skip(declared)
+        .map(|(name, _)| name)
+        .collect()
+}
+
+/* unions of string literals are bound as enums whose variants are named after the strings */
+fn process_string_enum(name: String, values: Vec<String>) -> wb::StringEnumDesc {
+    let variants = values
+        .into_iter()
+        .map(|value| {
+            let variant = value.to_pascal_case();
+            /* variants must start with a letter */
+            match variant.chars().next() {
+                Some(first) if first.is_alphabetic() => (variant, value),
+                _ => (format!("Value{}", variant), value),
             }
-            let return_param = wb::ParamDesc::new(return_type, false, optional);
-            Ok(wb::FunctionDesc::new(attributes,
-                name.to_owned(),
-                fn_arguments,
-                Some(return_param)))
+        })
+        .collect();
+    wb::StringEnumDesc::new(name, variants)
+}
+
+fn process_literal_properties(int_name: &str,
+                              properties: Vec<(String, wb::TypeDesc)>,
+                              literals: &mut Vec<(String, wb::TypeDesc)>) -> Vec<wb::FunctionDesc> {
+    let mut int_methods = Vec::new();
+    for (js_name, type_desc) in properties {
+        match process_property_type(&js_name, type_desc, false, false, false) {
+            Ok(mut property) => int_methods.append(&mut property),
+            Err(error) => eprintln!("warning: skipping member {}::{}: {}", int_name, js_name, error),
         }
     }
-    else {
-        Ok(wb::FunctionDesc::new(attributes,
-            name.to_owned(),
-            fn_arguments,
-            None))
+    for method in int_methods.iter_mut() {
+        name_type_literals(int_name, method, literals);
     }
+    int_methods
 }
 
-fn process_class(cls_declaration: &swc_ecma_ast::ClassDecl, 
-                 cls_overrides: &mut ClassOverride,
-                 comments: &swc_common::comments::Comments) -> wb::ClassDesc {
-    let cls_name = cls_declaration.ident.sym.to_string();
+fn name_type_literals(owner: &str,
+                      function: &mut wb::FunctionDesc,
+                      literals: &mut Vec<(String, wb::TypeDesc)>) {
+    /* the value of a setter shares the name of its property */
+    let setter = function.attributes
+        .iter()
+        .any(|(attribute, _)| attribute == "setter");
+    let member_name = match setter {
+        true => function.name.trim_start_matches("set_"),
+        false => function.name.as_str(),
+    };
+    let name = format!("{}{}", owner, member_name.to_pascal_case());
+    for (arg_name, param) in function.arguments.iter_mut() {
+        match setter {
+            true => name_type_literal(&name, &mut param.type_desc, literals),
+            false => name_type_literal(&format!("{}{}", name, arg_name.to_pascal_case()),
+                                       &mut param.type_desc,
+                                       literals),
+        }
+    }
+    if let Some(returns) = function.returns.as_mut() {
+        name_type_literal(&name, &mut returns.type_desc, literals);
+    }
+}
+
+fn name_type_literal(name: &str,
+                     type_desc: &mut wb::TypeDesc,
+                     literals: &mut Vec<(String, wb::TypeDesc)>) {
+    match type_desc {
+        wb::TypeDesc::Object(properties) if !properties.is_empty() => {},
+        wb::TypeDesc::Literals(_) => {},
+        /* intersections can only extend classes and merge the properties of object literals */
+        wb::TypeDesc::Intersection(types) => {
+            let lowerable = types
+                .iter()
+                .all(|type_desc| matches!(type_desc, wb::TypeDesc::Class(_) | wb::TypeDesc::Object(_)));
+            if !lowerable {
+                eprintln!("warning: binding {} as JsValue: cannot lower intersection {:?}", name, types);
+                *type_desc = wb::TypeDesc::Any;
+                return;
+            }
+        },
+        wb::TypeDesc::Array(inner_type) |
+        wb::TypeDesc::Promise(inner_type) |
+        wb::TypeDesc::Record(inner_type) => return name_type_literal(name, inner_type, literals),
+        wb::TypeDesc::Generic(_, types) |
+        wb::TypeDesc::Union(types) |
+        wb::TypeDesc::Tuple(types) => {
+            for inner_type in types.iter_mut() {
+                name_type_literal(name, inner_type, literals);
+            }
+            return;
+        },
+        wb::TypeDesc::Function(parameters, return_type) => {
+            for (parameter_name, parameter) in parameters.iter_mut() {
+                name_type_literal(&format!("{}{}", name, parameter_name.to_pascal_case()), parameter, literals);
+            }
+            if let Some(return_type) = return_type {
+                name_type_literal(name, return_type, literals);
+            }
+            return;
+        },
+        _ => return,
+    }
+    /* identical literals share a type while different ones with the same name are numbered */
+    let mut unique_name = name.to_owned();
+    let mut count = 1;
+    loop {
+        match literals.iter().find(|(other_name, _)| other_name == &unique_name) {
+            Some((_, other)) if other == type_desc => break,
+            Some(_) => {
+                count += 1;
+                unique_name = format!("{}{}", name, count);
+            },
+            None => {
+                literals.push((unique_name.clone(), type_desc.clone()));
+                break;
+            }
+        }
+    }
+    *type_desc = wb::TypeDesc::Class(unique_name);
+}
+
+/* the parameters of function types, which are only used to describe the signatures of callbacks */
+fn process_callback_parameter(index: usize, parameter: &swc_ecma_ast::TsFnParam)
+    -> Result<(String, wb::TypeDesc), String> {
+    match parameter {
+        swc_ecma_ast::TsFnParam::Ident(ident) => {
+            let type_desc = match &ident.type_ann {
+                Some(type_ann) => process_type(&type_ann.type_ann)?,
+                None => wb::TypeDesc::Any,
+            };
+            /* optional parameters may be undefined */
+            let type_desc = if ident.optional {
+                wb::TypeDesc::Union(vec![type_desc, wb::TypeDesc::Undefined])
+            }
+            else {
+                type_desc
+            };
+            Ok((escape_keyword(ident.sym.to_snake_case()), type_desc))
+        },
+        swc_ecma_ast::TsFnParam::Rest(rest) => {
+            let name = match &*rest.arg {
+                swc_ecma_ast::Pat::Ident(ident) => escape_keyword(ident.sym.to_snake_case()),
+                _ => format!("arg_{}", index),
+            };
+            let type_desc = match &rest.type_ann {
+                Some(type_ann) => process_type(&type_ann.type_ann)?,
+                None => wb::TypeDesc::Array(Box::new(wb::TypeDesc::Any)),
+            };
+            Ok((name, type_desc))
+        },
+        /* destructured parameters are passed as a single value */
+        _ => Ok((format!("arg_{}", index), wb::TypeDesc::Any)),
+    }
+}
+
+fn process_parameter(parameter: &swc_ecma_ast::Param)
+    -> Result<(String, wb::ParamDesc), String> {
+    match &parameter.pat {
+        swc_ecma_ast::Pat::Ident(identifier) => process_ident_parameter(identifier),
+        swc_ecma_ast::Pat::Rest(rest) => process_rest_parameter(rest),
+        _ => Err(format!("cannot process parameter without identifier {:?}", parameter))
+    }
+}
+
+/* parameters of the method signatures in interfaces */
+fn process_fn_parameter(parameter: &swc_ecma_ast::TsFnParam)
+    -> Result<(String, wb::ParamDesc), String> {
+    match parameter {
+        swc_ecma_ast::TsFnParam::Ident(identifier) => process_ident_parameter(identifier),
+        swc_ecma_ast::TsFnParam::Rest(rest) => process_rest_parameter(rest),
+        _ => Err(format!("cannot process parameter without identifier {:?}", parameter))
+    }
+}
+
+fn process_ident_parameter(identifier: &swc_ecma_ast::Ident)
+    -> Result<(String, wb::ParamDesc), String> {
+    if let Some(ts_type) = &identifier.type_ann {
+        let name = escape_keyword(identifier.sym.to_snake_case());
+        let (type_desc, nullable) = process_optional_type(process_type(&ts_type.type_ann)?);
+        Ok((name, wb::ParamDesc::new(type_desc, false, identifier.optional || nullable)))
+    }
+    else {
+        Err("cannot process parameter without type annotation".to_owned())
+    }
+}
+
+fn process_rest_parameter(rest: &swc_ecma_ast::RestPat)
+    -> Result<(String, wb::ParamDesc), String> {
+    if let (swc_ecma_ast::Pat::Ident(identifier), Some(ts_type)) = (&*rest.arg, &rest.type_ann) {
+        let name = escape_keyword(identifier.sym.to_snake_case());
+        let type_desc = process_type(&ts_type.type_ann)?;
+        /* slices of numbers are passed directly, everything else is collected into an array */
+        let reference = !matches!(&type_desc, wb::TypeDesc::Array(inner)
+                                  if matches!(**inner, wb::TypeDesc::Number));
+        let mut param_desc = wb::ParamDesc::new(type_desc, reference, false);
+        param_desc.variadic = true;
+        Ok((name, param_desc))
+    }
+    else {
+        Err("cannot process rest parameter without identifier or type annotation".to_owned())
+    }
+}
+
+/* handles the special option case, i.e., T | null and T | undefined */
+fn process_optional_type(type_desc: wb::TypeDesc) -> (wb::TypeDesc, bool) {
+    match type_desc {
+        wb::TypeDesc::Union(mut union) => {
+            let count = union.len();
+            union.retain(|type_desc| !matches!(type_desc, wb::TypeDesc::Null | wb::TypeDesc::Undefined));
+            let optional = union.len() != count;
+            match union.len() {
+                0 => (wb::TypeDesc::Null, optional),
+                1 => (union.remove(0), optional),
+                _ => (wb::TypeDesc::Union(union), optional),
+            }
+        },
+        type_desc => (type_desc, false)
+    }
+}
+
+/* binds a function under a new Rust name while keeping its JavaScript name, the variants of
+   the function keep their suffixes */
+fn rename_function(fn_desc: &mut wb::FunctionDesc, js_name: &str, name: &str) {
+    let snake_case_name = escape_keyword(js_name.to_snake_case());
+    fn_desc.name = fn_desc.name.replacen(&snake_case_name, name, 1);
+    let mut named = false;
+    for (attribute, value) in fn_desc.attributes.iter_mut() {
+        match attribute.as_str() {
+            /* accessors are named by their attribute and constructors do not need a name */
+            "getter" | "setter" | "js_name" => {
+                *value = Some(js_name.to_owned());
+                named = true;
+            },
+            "constructor" => named = true,
+            _ => {},
+        }
+    }
+    if !named {
+        fn_desc.attributes.push((String::from("js_name"), Some(js_name.to_owned())));
+    }
+}
+
+/* appends an underscore to identifiers that are reserved in Rust */
+fn escape_keyword(ident: String) -> String {
+    const KEYWORDS: &[&str] = &[
+        "as", "break", "const", "continue", "crate", "else", "enum", "extern",
+        "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod",
+        "move", "mut", "pub", "ref", "return", "self", "static", "struct", "super",
+        "trait", "true", "type", "unsafe", "use", "where", "while", "async",
+        "await", "dyn", "abstract", "become", "box", "do", "final", "macro",
+        "override", "priv", "typeof", "unsized", "virtual", "yield", "try",
+    ];
+    if KEYWORDS.contains(&ident.as_str()) {
+        format!("{}_", ident)
+    }
+    else {
+        ident
+    }
+}
+
+fn process_property(js_name: &str,
+                    type_ann: &swc_ecma_ast::TsType,
+                    readonly: bool,
+                    optional: bool,
+                    is_static: bool)
+    -> Result<Vec<wb::FunctionDesc>, String> {
+    process_property_type(js_name, process_type(type_ann)?, readonly, optional, is_static)
+}
+
+fn process_property_type(js_name: &str,
+                         type_desc: wb::TypeDesc,
+                         readonly: bool,
+                         optional: bool,
+                         is_static: bool)
+    -> Result<Vec<wb::FunctionDesc>, String> {
+    let (type_desc, nullable) = process_optional_type(type_desc);
+    /* check that the type can be represented before generating the accessors */
+    let convertible = match &type_desc {
+        wb::TypeDesc::Union(types) => wb::UnionDesc::new(types).is_ok(),
+        type_desc => <&str>::try_from(type_desc).is_ok(),
+    };
+    if !convertible {
+        return Err(format!("cannot convert {:?}", type_desc));
+    }
+    /* unions are read as JsValue and written through the generated enum */
+    let getter_type_desc = match &type_desc {
+        wb::TypeDesc::Union(_) => wb::TypeDesc::Any,
+        type_desc => type_desc.clone(),
+    };
+    let optional = optional || nullable;
+    let snake_case_name = js_name.to_snake_case();
+    /* generate the getter, naming the property explicitly if it cannot be inferred */
+    let name = escape_keyword(snake_case_name.clone());
+    let mut getter_attributes = vec![
+        (String::from("getter"), Some(js_name.to_owned()).filter(|_| name != js_name)),
+    ];
+    let mut getter_arguments = Vec::new();
+    /* static properties are accessed through the class instead of an instance */
+    if !is_static {
+        getter_attributes.insert(0, (String::from("method"), None));
+        getter_arguments.push((String::from("this"), wb::ParamDesc::new(wb::TypeDesc::This, true, false)));
+    }
+    let mut getter = wb::FunctionDesc::new(getter_attributes,
+        name,
+        getter_arguments,
+        Some(wb::ParamDesc::new(getter_type_desc, false, optional)));
+    getter.is_static = is_static;
+    if readonly {
+        return Ok(vec![getter]);
+    }
+    /* generate the setter, wasm-bindgen infers the property by stripping set_ */
+    let mut setter_attributes = vec![
+        (String::from("setter"), Some(js_name.to_owned()).filter(|_| snake_case_name != js_name)),
+    ];
+    let mut setter_arguments = Vec::new();
+    if !is_static {
+        setter_attributes.insert(0, (String::from("method"), None));
+        setter_arguments.push((String::from("this"), wb::ParamDesc::new(wb::TypeDesc::This, true, false)));
+    }
+    setter_arguments.push((String::from("value"), wb::ParamDesc::new(type_desc, false, optional)));
+    let mut setter = wb::FunctionDesc::new(setter_attributes,
+        format!("set_{}", snake_case_name),
+        setter_arguments,
+        None);
+    setter.is_static = is_static;
+    Ok(vec![getter, setter])
+}
+
+/* the type parameters of a function are bound as their constraints or as JsValue if they are
+   unconstrained, e.g., parse<T extends Object3D>(): T returns an Object3D */
+fn process_type_params(type_params: Option<&swc_ecma_ast::TsTypeParamDecl>)
+    -> Result<HashMap<String, wb::TypeDesc>, String> {
+    type_params
+        .iter()
+        .flat_map(|type_params| &type_params.params)
+        .map(|type_param| {
+            let constraint = match &type_param.constraint {
+                Some(constraint) => process_type(constraint)?,
+                None => wb::TypeDesc::Any,
+            };
+            Ok((type_param.name.sym.to_string(), constraint))
+        })
+        .collect()
+}
+
+fn substitute_type_param(type_desc: &mut wb::TypeDesc, substitutions: &HashMap<String, wb::TypeDesc>) {
+    if let wb::TypeDesc::Class(identifier) = type_desc {
+        if let Some(constraint) = substitutions.get(identifier.as_str()) {
+            *type_desc = constraint.clone();
+        }
+    }
+}
+
+fn process_function(name: &str,
+                    attributes: Vec<(String, Option<String>)>,
+                    type_params: Option<&swc_ecma_ast::TsTypeParamDecl>,
+                    parameters: &[&swc_ecma_ast::Param],
+                    return_type: &Option<&swc_ecma_ast::TsType>)
+    -> Result<wb::FunctionDesc, String> {
+    /* process the parameters */
+    let fn_arguments = parameters
+        .iter()
+        .map(|p| process_parameter(p))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut fn_desc = wb::FunctionDesc::new(attributes,
+        name.to_owned(),
+        fn_arguments,
+        process_return_type(return_type)?);
+    let substitutions = process_type_params(type_params)?;
+    fn_desc.visit_types_mut(&mut |type_desc| substitute_type_param(type_desc, &substitutions));
+    Ok(fn_desc)
+}
+
+fn process_return_type(return_type: &Option<&swc_ecma_ast::TsType>)
+    -> Result<Option<wb::ParamDesc>, String> {
+    if let Some(return_type) = return_type {
+        let return_type = process_type(return_type)?;
+        // TODO do not filter out TsVoid here
+        if matches!(return_type, wb::TypeDesc::Void) {
+            Ok(None)
+        }
+        else {
+            let (mut return_type, optional) = process_optional_type(return_type);
+            /* returned unions are left to the caller to convert from JsValue */
+            if let wb::TypeDesc::Union(_) = return_type {
+                return_type = wb::TypeDesc::Any;
+            }
+            Ok(Some(wb::ParamDesc::new(return_type, false, optional)))
+        }
+    }
+    else {
+        Ok(None)
+    }
+}
+
+/* generates a binding for each prefix of the trailing optional arguments if
+   variants were requested, e.g., new, new_with_radius, new_with_radius_and_width_segments */
+fn process_variants(fn_desc: wb::FunctionDesc, variant_names: Option<&Vec<String>>)
+    -> Vec<wb::FunctionDesc> {
+    let variant_names = match variant_names {
+        Some(variant_names) => variant_names,
+        None => return vec![fn_desc],
+    };
+    let required = fn_desc.arguments
+        .iter()
+        .rposition(|(_, param)| !param.optional)
+        .map_or(0, |position| position + 1);
+    let count = fn_desc.arguments.len() - required + 1;
+    if count == 1 {
+        return vec![fn_desc];
+    }
+    let variant_names = if variant_names.is_empty() {
+        (required..=fn_desc.arguments.len())
+            .map(|length| match length - required {
+                0 => fn_desc.name.clone(),
+                _ => format!("{}_with_{}", fn_desc.name, fn_desc.arguments[required..length]
+                    .iter()
+                    .map(|(arg_name, _)| arg_name.clone())
+                    .collect::<Vec<_>>()
+                    .join("_and_"))
+            })
+            .collect()
+    }
+    else if variant_names.len() == count {
+        variant_names.clone()
+    }
+    else {
+        eprintln!("warning: {} variants requested for {} but {} were named",
+                  count, fn_desc.name, variant_names.len());
+        return vec![fn_desc];
+    };
+    /* keep binding to the original JavaScript name */
+    let js_name_set = fn_desc.attributes
+        .iter()
+        .any(|(attribute, _)| attribute == "js_name" || attribute == "constructor");
+    variant_names
+        .into_iter()
+        .enumerate()
+        .map(|(index, variant_name)| {
+            let mut variant = fn_desc.clone();
+            variant.arguments.truncate(required + index);
+            for (_, param) in variant.arguments.iter_mut().skip(required) {
+                param.optional = false;
+            }
+            if !js_name_set && variant_name != fn_desc.name {
+                variant.attributes.push((String::from("js_name"), Some(fn_desc.name.clone())));
+            }
+            variant.name = variant_name;
+            variant
+        })
+        .collect()
+}
+
+fn process_class(cls_declaration: &swc_ecma_ast::ClassDecl, 
+                 cls_overrides: &mut ClassOverride,
+                 comments: &swc_common::comments::Comments) -> wb::ClassDesc {
+    let cls_name = cls_declaration.ident.sym.to_string();
     let mut cls_attributes = Vec::new();
     let mut cls_methods = Vec::new();
+    let mut cls_super_type_args = Vec::new();
     /* handle super class */
     if let Some(class) = &cls_declaration.class.super_class {
         if let swc_ecma_ast::Expr::Ident(ident) = &**class {
             cls_attributes.push((String::from("extends"), Some(ident.sym.to_string())));
+            /* handle generic super class */
+            if let Some(super_type_params) = &cls_declaration.class.super_type_params {
+                let super_type_args = super_type_params.params
+                    .iter()
+                    .map(|param| process_type(param))
+                    .collect::<Result<Vec<_>, _>>();
+                match super_type_args {
+                    Ok(super_type_args) => cls_super_type_args = super_type_args,
+                    Err(error) => {
+                        eprintln!("warning: skipping the type arguments of the super class of {}: {}", cls_name, error);
+                    }
+                }
+            }
         }
     }
     /* handle methods */
     for class_member in &cls_declaration.class.body {
         match class_member {
             swc_ecma_ast::ClassMember::Constructor(constructor) => {
-                let fn_overrides = cls_overrides.methods.get_mut("constructor");
-                if let Some(fn_overrides) = fn_overrides {
-                    /* by moving these function descriptors out of the vector, 
-                       we ensure that overloaded constructors are bound only once */
-                    cls_methods.append(fn_overrides);
+                let mut fn_override = cls_overrides.method("constructor");
+                if let Some(mut fn_descs) = fn_override.as_mut().and_then(|fn_override| fn_override.replacement()) {
+                    cls_methods.append(&mut fn_descs);
                 }
                 else {
                     let fn_attributes = vec![(String::from("constructor"), None)];
@@ -476,16 +1747,21 @@ fn process_class(cls_declaration: &swc_ecma_ast::ClassDecl,
                         })
                         .collect();
                     let fn_desc = 
-                        process_function(&fn_name, fn_attributes, &fn_parameters, &None);
+                        process_function(&fn_name, fn_attributes, None, &fn_parameters, &None);
                     match fn_desc {
                         Ok(mut fn_desc) => {
                             let fn_return_type = 
                                 wb::ParamDesc::new(wb::TypeDesc::This, false, false);
                                 fn_desc.returns = Some(fn_return_type);
-                            cls_methods.push(fn_desc);
+                            let fn_variants = cls_overrides.variants("constructor");
+                            let mut fn_descs = process_variants(fn_desc, fn_variants);
+                            if let Some(fn_override) = cls_overrides.method("constructor") {
+                                fn_override.apply("constructor", &mut fn_descs);
+                            }
+                            cls_methods.append(&mut fn_descs);
                         },
                         Err(error) => {
-                            panic!(format!("Error processing {}::{}: {}", cls_name, fn_name, error));
+                            eprintln!("warning: skipping constructor of {}: {}", cls_name, error);
                         }
                     }
                 }
@@ -495,22 +1771,25 @@ fn process_class(cls_declaration: &swc_ecma_ast::ClassDecl,
                     let fn_deprecated = comments
                         .take_leading_comments(class_method.span.lo())
                         .and_then(|mut v| v.pop())
-                        .and_then(|c| Some(c.text.contains("@deprecated")))
-                        .unwrap_or(false);
+                        .is_some_and(|c| c.text.contains("@deprecated"));
                     if fn_deprecated {
                         continue;
                     }
                     let function = &class_method.function;
                     if let swc_ecma_ast::PropName::Ident(ident) = &class_method.key {
-                        let fn_overrides = cls_overrides.methods.get_mut(&ident.sym as &str);
-                        if let Some(fn_overrides) = fn_overrides {
-                            /* by moving these function descriptors out of the vector, 
-                               we ensure that overloaded methods are bound only once */
-                            cls_methods.append(fn_overrides);
+                        let mut fn_override = cls_overrides.method(&ident.sym);
+                        if let Some(mut fn_descs) = fn_override.as_mut().and_then(|fn_override| fn_override.replacement()) {
+                            cls_methods.append(&mut fn_descs);
                         }
                         else {
                             let fn_name = ident.sym.to_snake_case();
-                            let mut fn_attributes = vec![(String::from("method"), None)];
+                            /* static methods are bound with static_method_of by the writer */
+                            let mut fn_attributes = if class_method.is_static {
+                                Vec::new()
+                            }
+                            else {
+                                vec![(String::from("method"), None)]
+                            };
                             if ident.sym.to_string() != fn_name {
                                 fn_attributes.push((String::from("js_name"), Some(ident.sym.to_string()))); 
                             }
@@ -525,31 +1804,966 @@ fn process_class(cls_declaration: &swc_ecma_ast::ClassDecl,
                                 None => None
                             };
                             let fn_desc =
-                                process_function(&fn_name, fn_attributes, &fn_parameters, &fn_return_type);
+                                process_function(&fn_name,
+                                                 fn_attributes,
+                                                 function.type_params.as_ref(),
+                                                 &fn_parameters,
+                                                 &fn_return_type);
                             match fn_desc {
                                 Ok(mut fn_desc) => {
-                                    let this_param = wb::ParamDesc::new(wb::TypeDesc::This, true, false);
-                                    fn_desc.arguments.insert(0, (String::from("this"), this_param));
-                                    cls_methods.push(fn_desc);
+                                    if class_method.is_static {
+                                        fn_desc.is_static = true;
+                                    }
+                                    else {
+                                        let this_param = wb::ParamDesc::new(wb::TypeDesc::This, true, false);
+                                        fn_desc.arguments.insert(0, (String::from("this"), this_param));
+                                    }
+                                    let fn_variants = cls_overrides.variants(&ident.sym);
+                                    let mut fn_descs = process_variants(fn_desc, fn_variants);
+                                    if let Some(fn_override) = cls_overrides.method(&ident.sym) {
+                                        fn_override.apply(&ident.sym, &mut fn_descs);
+                                    }
+                                    cls_methods.append(&mut fn_descs);
                                 },
                                 Err(error) => {
-                                    panic!(format!("Error processing {}::{}: {}", cls_name, fn_name, error));
+                                    eprintln!("warning: skipping method {}::{}: {}", cls_name, ident.sym, error);
                                 }
                             }
                         }
                     }
                 }
             },
+            swc_ecma_ast::ClassMember::ClassProp(class_prop) => {
+                /* only public properties are accessible from JavaScript */
+                if matches!(class_prop.accessibility,
+                            Some(swc_ecma_ast::Accessibility::Private) |
+                            Some(swc_ecma_ast::Accessibility::Protected)) {
+                    continue;
+                }
+                let prop_deprecated = comments
+                    .take_leading_comments(class_prop.span.lo())
+                    .and_then(|mut v| v.pop())
+                    .is_some_and(|c| c.text.contains("@deprecated"));
+                if prop_deprecated {
+                    continue;
+                }
+                if let swc_ecma_ast::Expr::Ident(ident) = &*class_prop.key {
+                    /* properties share the namespace of methods in JavaScript */
+                    let mut prop_override = cls_overrides.method(&ident.sym);
+                    if let Some(mut prop_desc) = prop_override.as_mut().and_then(|prop_override| prop_override.replacement()) {
+                        cls_methods.append(&mut prop_desc);
+                    }
+                    else if let Some(type_ann) = &class_prop.type_ann {
+                        let prop_desc = process_property(&ident.sym,
+                                                         &type_ann.type_ann,
+                                                         class_prop.readonly,
+                                                         class_prop.is_optional,
+                                                         class_prop.is_static);
+                        match prop_desc {
+                            Ok(mut prop_desc) => {
+                                if let Some(prop_override) = prop_override {
+                                    prop_override.apply(&ident.sym, &mut prop_desc);
+                                }
+                                cls_methods.append(&mut prop_desc);
+                            },
+                            Err(error) => {
+                                eprintln!("warning: skipping property {}::{}: {}", cls_name, ident.sym, error);
+                            }
+                        }
+                    }
+                }
+            },
             _ => ()
         }
     }
-    wb::ClassDesc::new(cls_name, cls_attributes, cls_methods)
+    process_accessors(&mut cls_methods);
+    process_overloads(&mut cls_methods);
+    let mut cls_desc = wb::ClassDesc::new(cls_name, cls_attributes, cls_methods);
+    cls_desc.super_type_args = cls_super_type_args;
+    /* capture the generic type parameters */
+    if let Some(type_params) = &cls_declaration.class.type_params {
+        cls_desc.type_params = type_params.params
+            .iter()
+            .map(|param| param.name.sym.to_string())
+            .collect();
+    }
+    cls_desc
+}
+
+/* drops accessors that clash with methods, e.g., usage/setUsage in BufferAttribute */
+fn process_accessors(cls_methods: &mut Vec<wb::FunctionDesc>) {
+    let is_accessor = |fn_desc: &wb::FunctionDesc| fn_desc.attributes
+        .iter()
+        .any(|(attribute, _)| attribute == "getter" || attribute == "setter");
+    let method_names : Vec<String> = cls_methods
+        .iter()
+        .filter(|fn_desc| !is_accessor(fn_desc))
+        .map(|fn_desc| fn_desc.name.clone())
+        .collect();
+    cls_methods.retain(|fn_desc| !is_accessor(fn_desc) || !method_names.contains(&fn_desc.name));
+}
+
+/* collects the members of an interface, errors are reported once the interface is bound */
+fn collect_interface(int_declaration: &swc_ecma_ast::TsInterfaceDecl,
+                     comments: &swc_common::comments::Comments) -> InterfaceDecl {
+    let mut int_members = Vec::new();
+    for int_member in &int_declaration.body.body {
+        let (span, key) = match int_member {
+            swc_ecma_ast::TsTypeElement::TsPropertySignature(property) if !property.computed =>
+                (property.span, &property.key),
+            swc_ecma_ast::TsTypeElement::TsMethodSignature(method) if !method.computed =>
+                (method.span, &method.key),
+            _ => continue,
+        };
+        let member_deprecated = comments
+            .take_leading_comments(span.lo())
+            .and_then(|mut v| v.pop())
+            .is_some_and(|c| c.text.contains("@deprecated"));
+        let ident = match &**key {
+            swc_ecma_ast::Expr::Ident(ident) if !member_deprecated => ident,
+            _ => continue,
+        };
+        let member_desc = match int_member {
+            swc_ecma_ast::TsTypeElement::TsPropertySignature(property) => {
+                match &property.type_ann {
+                    Some(type_ann) => process_property(&ident.sym,
+                                                       &type_ann.type_ann,
+                                                       property.readonly,
+                                                       property.optional,
+                                                       false),
+                    None => Err("cannot process property without type annotation".to_owned()),
+                }
+            },
+            swc_ecma_ast::TsTypeElement::TsMethodSignature(method) => {
+                let fn_name = escape_keyword(ident.sym.to_snake_case());
+                let mut fn_attributes = vec![(String::from("method"), None)];
+                if *ident.sym != *fn_name {
+                    fn_attributes.push((String::from("js_name"), Some(ident.sym.to_string())));
+                }
+                let fn_arguments = method.params
+                    .iter()
+                    .map(process_fn_parameter)
+                    .collect::<Result<Vec<_>, _>>();
+                let fn_return_type = method.type_ann
+                    .as_ref()
+                    .map(|fn_return_type| &*fn_return_type.type_ann);
+                fn_arguments
+                    .and_then(|mut fn_arguments| {
+                        let this_param = wb::ParamDesc::new(wb::TypeDesc::This, true, false);
+                        fn_arguments.insert(0, (String::from("this"), this_param));
+                        let fn_returns = process_return_type(&fn_return_type)?;
+                        let mut fn_desc = wb::FunctionDesc::new(fn_attributes, fn_name, fn_arguments, fn_returns);
+                        let substitutions = process_type_params(method.type_params.as_ref())?;
+                        fn_desc.visit_types_mut(&mut |type_desc| substitute_type_param(type_desc, &substitutions));
+                        Ok(vec![fn_desc])
+                    })
+            },
+            _ => continue,
+        };
+        int_members.push((ident.sym.to_string(), member_desc));
+    }
+    let int_extends = int_declaration.extends
+        .iter()
+        .filter_map(|extends| match &extends.expr {
+            swc_ecma_ast::TsEntityName::Ident(ident) => Some(ident.sym.to_string()),
+            _ => None,
+        })
+        .collect();
+    let int_type_params = int_declaration.type_params
+        .iter()
+        .flat_map(|type_params| &type_params.params)
+        .map(|param| param.name.sym.to_string())
+        .collect();
+    InterfaceDecl {
+        members: int_members,
+        extends: int_extends,
+        type_params: int_type_params,
+    }
+}
+
+/* the members of an interface followed by the members that it inherits and does not redeclare */
+fn flatten_interface(int_name: &str,
+                     interfaces: &Interfaces,
+                     visited: &mut Vec<String>) -> Vec<(String, Result<Vec<wb::FunctionDesc>, String>)> {
+    let mut int_members = Vec::new();
+    if visited.iter().any(|name| name == int_name) {
+        return int_members;
+    }
+    visited.push(int_name.to_owned());
+    if let Some(interface) = interfaces.get(int_name) {
+        int_members.extend(interface.members.iter().cloned());
+        for super_name in &interface.extends {
+            for super_member in flatten_interface(super_name, interfaces, visited) {
+                if !interface.members.iter().any(|(name, _)| name == &super_member.0) {
+                    int_members.push(super_member);
+                }
+            }
+        }
+    }
+    int_members
+}
+
+/* interfaces are bound as extern types that are accessed structurally */
+fn process_interface(int_name: &str,
+                     interfaces: &Interfaces,
+                     int_overrides: Option<&mut ClassOverride>) -> wb::ClassDesc {
+    let mut int_methods = Vec::new();
+    let mut int_overrides = int_overrides;
+    for (member_name, member_desc) in flatten_interface(int_name, interfaces, &mut Vec::new()) {
+        let mut member_override = int_overrides
+            .as_mut()
+            .and_then(|int_overrides| int_overrides.method(&member_name));
+        if let Some(mut member_desc) = member_override.as_mut().and_then(|member_override| member_override.replacement()) {
+            int_methods.append(&mut member_desc);
+            continue;
+        }
+        match member_desc {
+            Ok(mut member_desc) => {
+                if let Some(member_override) = member_override {
+                    member_override.apply(&member_name, &mut member_desc);
+                }
+                int_methods.append(&mut member_desc);
+            },
+            Err(error) => {
+                eprintln!("warning: skipping member {}::{}: {}", int_name, member_name, error);
+            }
+        }
+    }
+    process_accessors(&mut int_methods);
+    process_overloads(&mut int_methods);
+    let int_attributes = vec![(String::from("extends"), Some(String::from("js_sys::Object")))];
+    let mut int_desc = wb::ClassDesc::new(int_name.to_owned(), int_attributes, int_methods);
+    if let Some(interface) = interfaces.get(int_name) {
+        int_desc.type_params = interface.type_params.clone();
+    }
+    int_desc
+}
+
+fn find_generic_instance(name: &str,
+                         type_args: &[wb::TypeDesc],
+                         generic_instances: &GenericInstances) -> Option<String> {
+    generic_instances
+        .get(name)?
+        .iter()
+        .find(|instance| instance.1 == type_args)
+        .map(|instance| instance.0.clone())
+}
+
+/* instantiates a generic class for each set of type arguments declared in the overrides,
+   e.g., Curve<Vector2> as Curve2, and resolves references to instances of generic classes */
+fn process_generics(class: wb::ClassDesc, generic_instances: &GenericInstances)
+    -> Vec<wb::ClassDesc> {
+    let instances = if class.type_params.is_empty() {
+        vec![(class.name.clone(), Vec::new())]
+    }
+    else if let Some(instances) = generic_instances.get(&class.name) {
+        instances.clone()
+    }
+    else {
+        /* without any declared instances, the type parameters are bound as JsValue */
+        vec![(class.name.clone(), vec![wb::TypeDesc::Any; class.type_params.len()])]
+    };
+    instances
+        .into_iter()
+        .filter(|(instance_name, type_args)| {
+            if type_args.len() != class.type_params.len() {
+                eprintln!("warning: skipping {}: {} expects {} type arguments",
+                          instance_name, class.name, class.type_params.len());
+            }
+            type_args.len() == class.type_params.len()
+        })
+        .map(|(instance_name, type_args)| {
+            let mut instance = class.clone();
+            let substitutions : HashMap<&str, wb::TypeDesc> = class.type_params
+                .iter()
+                .map(String::as_str)
+                .zip(type_args)
+                .collect();
+            instance.visit_types_mut(&mut |type_desc| {
+                if let wb::TypeDesc::Class(identifier) = type_desc {
+                    if let Some(type_arg) = substitutions.get(identifier.as_str()) {
+                        *type_desc = type_arg.clone();
+                    }
+                }
+                else if let wb::TypeDesc::Generic(identifier, type_args) = type_desc {
+                    if let Some(name) = find_generic_instance(identifier, type_args, generic_instances) {
+                        *type_desc = wb::TypeDesc::Class(name);
+                    }
+                }
+            });
+            /* extend the matching instance of a generic super class */
+            let super_instance = instance.attributes
+                .iter()
+                .find(|attribute| attribute.0 == "extends")
+                .and_then(|attribute| attribute.1.as_ref())
+                .and_then(|super_class| find_generic_instance(super_class,
+                                                              &instance.super_type_args,
+                                                              generic_instances));
+            if let Some(super_instance) = super_instance {
+                for attribute in instance.attributes.iter_mut() {
+                    if attribute.0 == "extends" {
+                        attribute.1 = Some(super_instance.clone());
+                    }
+                }
+            }
+            /* bind the instance to the JavaScript class */
+            if instance_name != class.name {
+                instance.attributes.push((String::from("js_name"), Some(class.name.clone())));
+                for method in instance.methods.iter_mut() {
+                    let is_constructor = method.attributes
+                        .iter()
+                        .any(|attribute| attribute.0 == "constructor");
+                    if method.is_static || is_constructor {
+                        method.attributes.push((String::from("js_class"), Some(class.name.clone())));
+                    }
+                }
+                instance.name = instance_name;
+            }
+            instance
+        })
+        .collect()
+}
+
+/* maps the types of a module to the Rust types of the type map, the imports of the mapped
+   TypeScript types are replaced with the imports of the Rust types */
+fn process_type_map(mod_desc: &mut wb::ModuleDesc,
+                    type_map: &[&TypeMapping],
+                    imports: &mut HashMap<String, Vec<String>>) {
+    let find_mapping = |type_desc: &wb::TypeDesc| type_desc
+        .ts_name()
+        .and_then(|ts_name| type_map.iter().find(|mapping| mapping.ts_type.is_match(ts_name)));
+    /* the import of a TypeScript type is kept if it is mapped to a Rust type with the same name */
+    for symbols in imports.values_mut() {
+        symbols.retain(|symbol| match find_mapping(&wb::TypeDesc::Class(symbol.clone())) {
+            Some(mapping) => mapping.import.is_none() && &mapping.rust == symbol,
+            None => true,
+        });
+    }
+    /* the arguments are passed as the type map specifies before their types are mapped */
+    mod_desc.visit_functions_mut(&mut |fn_desc| {
+        for (_, param) in fn_desc.arguments.iter_mut() {
+            if let Some(reference) = find_mapping(&param.type_desc).and_then(|mapping| mapping.reference) {
+                param.reference = reference;
+            }
+        }
+    });
+    mod_desc.visit_types_mut(&mut |type_desc| {
+        if let Some(mapping) = find_mapping(type_desc) {
+            mapping.used.set(true);
+            if let Some((path, symbol)) = mapping.import.as_ref().and_then(|import| import.rsplit_once("::")) {
+                let symbols = imports.entry(path.to_owned()).or_default();
+                if !symbols.iter().any(|other| other == symbol) {
+                    symbols.push(symbol.to_owned());
+                }
+            }
+            *type_desc = wb::TypeDesc::Rust(mapping.rust.clone());
+        }
+    });
+}
+
+/* generates a name fragment for a type, e.g., Array(Number) -> f64_array */
+fn type_name_fragment(type_desc: &wb::TypeDesc) -> String {
+    match type_desc {
+        wb::TypeDesc::Any => String::from("js_value"),
+        wb::TypeDesc::Boolean => String::from("bool"),
+        wb::TypeDesc::Number => String::from("f64"),
+        wb::TypeDesc::String => String::from("str"),
+        wb::TypeDesc::Array(inner) => format!("{}_array", type_name_fragment(inner)),
+        wb::TypeDesc::Function(_, _) => String::from("function"),
+        wb::TypeDesc::Class(identifier) |
+        wb::TypeDesc::Builtin(identifier) |
+        wb::TypeDesc::Generic(identifier, _) => identifier.to_snake_case(),
+        wb::TypeDesc::Rust(identifier) => identifier
+            .rsplit("::")
+            .next()
+            .unwrap_or(identifier)
+            .to_snake_case(),
+        wb::TypeDesc::Union(types) => types
+            .iter()
+            .map(type_name_fragment)
+            .collect::<Vec<_>>()
+            .join("_or_"),
+        _ => String::from("value"),
+    }
+}
+
+/* the position of the only argument whose type differs between two overloads */
+fn union_position(fn_desc: &wb::FunctionDesc, other: &wb::FunctionDesc) -> Option<usize> {
+    if fn_desc.name != other.name ||
+       fn_desc.is_static != other.is_static ||
+       fn_desc.attributes != other.attributes ||
+       fn_desc.returns != other.returns ||
+       fn_desc.arguments.len() != other.arguments.len() {
+        return None;
+    }
+    let mut differences = fn_desc.arguments
+        .iter()
+        .zip(&other.arguments)
+        .enumerate()
+        .filter(|(_, (argument, other_argument))| argument != other_argument);
+    match (differences.next(), differences.next()) {
+        (Some((position, (argument, other_argument))), None) => {
+            let mergeable = argument.0 == other_argument.0 &&
+                argument.1.type_desc != other_argument.1.type_desc &&
+                argument.1.optional == other_argument.1.optional &&
+                !argument.1.variadic && !other_argument.1.variadic;
+            Some(position).filter(|_| mergeable)
+        },
+        _ => None,
+    }
+}
+
+/* merges overloads that only differ in the type of a single argument into one function
+   that takes a union, e.g., set(color: Color) and set(color: number) */
+fn merge_overloads(cls_methods: &mut Vec<wb::FunctionDesc>) {
+    let mut index = 0;
+    while index < cls_methods.len() {
+        let (previous, remaining) = cls_methods.split_at_mut(index);
+        let current = &remaining[0];
+        let merge = previous
+            .iter_mut()
+            .find_map(|fn_desc| union_position(fn_desc, current).map(|position| (fn_desc, position)));
+        if let Some((fn_desc, position)) = merge {
+            let param = &mut fn_desc.arguments[position].1;
+            let mut types = match &param.type_desc {
+                wb::TypeDesc::Union(types) => types.clone(),
+                type_desc => vec![type_desc.clone()],
+            };
+            match &current.arguments[position].1.type_desc {
+                wb::TypeDesc::Union(other_types) => types.extend(other_types.iter().cloned()),
+                type_desc => types.push(type_desc.clone()),
+            }
+            types.dedup();
+            /* the writer passes unions to JavaScript as a JsValue */
+            *param = wb::ParamDesc::new(wb::TypeDesc::Union(types), false, param.optional);
+            cls_methods.remove(index);
+        }
+        else {
+            index += 1;
+        }
+    }
+}
+
+/* gives overloaded functions distinct Rust names that are bound to the same JavaScript name */
+fn process_overloads(cls_methods: &mut Vec<wb::FunctionDesc>) {
+    /* overloads that map to the same Rust signature only need to be bound once */
+    let mut index = 0;
+    while index < cls_methods.len() {
+        let (previous, remaining) = cls_methods.split_at(index);
+        let current = &remaining[0];
+        let duplicate = previous.iter().any(|fn_desc| {
+            fn_desc.name == current.name &&
+            fn_desc.is_static == current.is_static &&
+            fn_desc.arguments == current.arguments &&
+            fn_desc.returns == current.returns
+        });
+        if duplicate {
+            cls_methods.remove(index);
+        }
+        else {
+            index += 1;
+        }
+    }
+    merge_overloads(cls_methods);
+    /* group the overloads by their names */
+    let mut overloads : HashMap<String, Vec<usize>> = HashMap::new();
+    for (index, fn_desc) in cls_methods.iter().enumerate() {
+        overloads.entry(fn_desc.name.clone()).or_default().push(index);
+    }
+    let mut names : Vec<String> = overloads.keys().cloned().collect();
+    /* sort the groups so that the generated names are deterministic */
+    let mut overloads : Vec<(String, Vec<usize>)> = overloads
+        .into_iter()
+        .filter(|(_, indices)| indices.len() > 1)
+        .collect();
+    overloads.sort_unstable();
+    for (name, indices) in overloads {
+        /* the overload with the fewest arguments keeps the original name if it is unique */
+        let arguments = |index: &usize| cls_methods[*index].arguments
+            .iter()
+            .filter(|(arg_name, _)| arg_name != "this")
+            .collect::<Vec<_>>();
+        let min_arity = indices.iter().map(|index| arguments(index).len()).min();
+        let mut renamed = indices.clone();
+        if indices.iter().filter(|index| Some(arguments(index).len()) == min_arity).count() == 1 {
+            renamed.retain(|index| Some(arguments(index).len()) != min_arity);
+        }
+        /* the arguments that tell an overload apart, i.e., the arguments whose types differ from the
+           type of the argument at the same position of one of the other overloads */
+        let distinct = |index: &usize| arguments(index)
+            .into_iter()
+            .enumerate()
+            .filter(|(position, (_, param))| indices.iter().any(|other| other != index &&
+                arguments(other)
+                    .get(*position)
+                    .is_none_or(|(_, other_param)| other_param.type_desc != param.type_desc)))
+            .map(|(_, argument)| argument)
+            .collect::<Vec<_>>();
+        /* overloads that take different numbers of arguments are told apart by their arity, the others by
+           the types of the distinct arguments, and failing that by their names without the underscores that
+           escape keywords */
+        let by_types = |index: &usize| {
+            let mut fragments = Vec::new();
+            for (_, param) in distinct(index) {
+                let fragment = type_name_fragment(&param.type_desc);
+                if !fragments.contains(&fragment) {
+                    fragments.push(fragment);
+                }
+            }
+            fragments.join("_and_")
+        };
+        let by_arity = |index: &usize| format!("{}_arguments", arguments(index).len());
+        let by_names = |index: &usize| distinct(index)
+            .iter()
+            .map(|(arg_name, _)| arg_name.trim_end_matches('_'))
+            .collect::<Vec<_>>()
+            .join("_and_");
+        let mut strategies : [&dyn Fn(&usize) -> String; 3] = [&by_types, &by_arity, &by_names];
+        if indices.iter().any(|index| Some(arguments(index).len()) != min_arity) {
+            strategies.swap(0, 1);
+        }
+        let mut candidates = None;
+        for strategy in strategies.iter() {
+            let fragments : Vec<String> = renamed.iter().map(strategy).collect();
+            let strategy_candidates : Vec<String> = fragments
+                .iter()
+                .map(|fragment| format!("{}_with_{}", name, fragment))
+                .collect();
+            let unique = strategy_candidates.iter().enumerate().all(|(position, candidate)| {
+                !names.contains(candidate) && !strategy_candidates[..position].contains(candidate)
+            });
+            if unique && !fragments.iter().any(String::is_empty) {
+                candidates = Some(strategy_candidates);
+                break;
+            }
+        }
+        /* fall back to numbering the overloads */
+        let candidates = candidates.unwrap_or_else(|| renamed
+            .iter()
+            .enumerate()
+            .map(|(position, _)| format!("{}_{}", name, position + 1))
+            .collect());
+        for (index, candidate) in renamed.into_iter().zip(candidates) {
+            let fn_desc = &mut cls_methods[index];
+            /* keep binding to the original JavaScript name */
+            let js_name_set = fn_desc.attributes
+                .iter()
+                .any(|(attribute, _)| attribute == "js_name");
+            let is_constructor = fn_desc.attributes
+                .iter()
+                .any(|(attribute, _)| attribute == "constructor");
+            if !js_name_set && !is_constructor {
+                fn_desc.attributes.push((String::from("js_name"), Some(name.clone())));
+            }
+            fn_desc.name = candidate.clone();
+            names.push(candidate);
+        }
+    }
+}
+
+/* the members of a namespace are bound as free functions, variables are accessed through
+   static getters and setters of the namespace object, which requires it to be exported */
+fn process_namespace(ns_declaration: &swc_ecma_ast::TsModuleDecl,
+                     ns_name: &str,
+                     js_namespace: Option<&str>,
+                     js_exports: Option<&[String]>,
+                     ns_overrides: Option<&mut ClassOverride>,
+                     comments: &swc_common::comments::Comments) -> wb::NamespaceDesc {
+    let mut ns_functions = Vec::new();
+    let mut ns_overrides = ns_overrides;
+    let ns_body = match &ns_declaration.body {
+        Some(swc_ecma_ast::TsNamespaceBody::TsModuleBlock(ns_block)) => &ns_block.body,
+        Some(swc_ecma_ast::TsNamespaceBody::TsNamespaceDecl(_)) => {
+            eprintln!("warning: skipping {}: nested namespaces are not supported", ns_name);
+            return wb::NamespaceDesc::new(ns_name.to_owned(), ns_functions);
+        },
+        None => return wb::NamespaceDesc::new(ns_name.to_owned(), ns_functions),
+    };
+    let ns_attributes = js_namespace
+        .map(|js_namespace| vec![(String::from("js_namespace"), Some(js_namespace.to_owned()))])
+        .unwrap_or_default();
+    for item in ns_body {
+        let export = match item {
+            swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::ExportDecl(export)) => export,
+            _ => continue,
+        };
+        let member_deprecated = comments
+            .take_leading_comments(export.span.lo())
+            .and_then(|mut v| v.pop())
+            .is_some_and(|c| c.text.contains("@deprecated"));
+        if member_deprecated {
+            continue;
+        }
+        let members : Vec<(String, Result<Vec<wb::FunctionDesc>, String>)> = match &export.decl {
+            swc_ecma_ast::Decl::Fn(fn_declaration) => {
+                let js_name = fn_declaration.ident.sym.to_string();
+                let fn_desc = process_fn_declaration(fn_declaration, ns_attributes.clone())
+                    .map(|fn_desc| {
+                        let fn_variants = ns_overrides
+                            .as_ref()
+                            .and_then(|ns_overrides| ns_overrides.variants(&js_name));
+                        process_variants(fn_desc, fn_variants)
+                    });
+                vec![(js_name, fn_desc)]
+            },
+            swc_ecma_ast::Decl::Var(var_declaration) => {
+                let readonly = var_declaration.kind == swc_ecma_ast::VarDeclKind::Const;
+                var_declaration.decls
+                    .iter()
+                    .filter_map(|declarator| match &declarator.name {
+                        swc_ecma_ast::Pat::Ident(ident) => Some(ident),
+                        _ => None,
+                    })
+                    .map(|ident| {
+                        let var_desc = match (&ident.type_ann, js_namespace) {
+                            (Some(type_ann), Some(_)) =>
+                                process_property(&ident.sym, &type_ann.type_ann, readonly, false, true),
+                            (None, _) => Err("cannot process variable without type annotation".to_owned()),
+                            (_, None) => Err("variables can only be accessed through a namespace".to_owned()),
+                        };
+                        /* the static accessors are bound to the namespace object by the writer */
+                        (ident.sym.to_string(), var_desc)
+                    })
+                    .collect()
+            },
+            swc_ecma_ast::Decl::Class(cls_declaration) => {
+                vec![(cls_declaration.ident.sym.to_string(),
+                      Err("classes in namespaces are not supported".to_owned()))]
+            },
+            _ => continue,
+        };
+        for (member_name, member_desc) in members {
+            /* members that are exported directly are only bound if the javascript module exports them */
+            if js_namespace.is_none() &&
+               !js_exports.is_some_and(|js_exports| js_exports.contains(&member_name)) {
+                continue;
+            }
+            let mut member_override = ns_overrides
+                .as_mut()
+                .and_then(|ns_overrides| ns_overrides.method(&member_name));
+            if let Some(mut member_desc) = member_override.as_mut().and_then(|member_override| member_override.replacement()) {
+                ns_functions.append(&mut member_desc);
+                continue;
+            }
+            match member_desc {
+                Ok(mut member_desc) => {
+                    if let Some(member_override) = member_override {
+                        member_override.apply(&member_name, &mut member_desc);
+                    }
+                    ns_functions.append(&mut member_desc);
+                },
+                Err(error) => {
+                    eprintln!("warning: skipping member {}::{}: {}", ns_name, member_name, error);
+                }
+            }
+        }
+    }
+    process_accessors(&mut ns_functions);
+    process_overloads(&mut ns_functions);
+    wb::NamespaceDesc::new(ns_name.to_owned(), ns_functions)
+}
+
+/* functions that are declared outside of a class, i.e., in a module or a namespace */
+fn process_fn_declaration(fn_declaration: &swc_ecma_ast::FnDecl,
+                          attributes: Vec<(String, Option<String>)>)
+    -> Result<wb::FunctionDesc, String> {
+    let js_name = fn_declaration.ident.sym.to_string();
+    let fn_name = escape_keyword(js_name.to_snake_case());
+    let mut fn_attributes = attributes;
+    if js_name != fn_name {
+        fn_attributes.push((String::from("js_name"), Some(js_name)));
+    }
+    let function = &fn_declaration.function;
+    let fn_parameters : Vec<&swc_ecma_ast::Param> = function.params.iter().collect();
+    let fn_return_type = function.return_type
+        .as_ref()
+        .map(|fn_return_type| &*fn_return_type.type_ann);
+    process_function(&fn_name, fn_attributes, function.type_params.as_ref(), &fn_parameters, &fn_return_type)
+}
+
+/* values exported by a module are bound as statics, e.g., REVISION or Object3DIdCount */
+fn process_static(js_name: &str, type_ann: &swc_ecma_ast::TsType) -> Result<wb::StaticDesc, String> {
+    let (type_desc, _) = process_optional_type(process_type(type_ann)?);
+    if let Err(error) = wb::StaticDesc::static_type(&type_desc) {
+        return Err(error.to_owned());
+    }
+    let name = js_name.to_screaming_snake_case();
+    let attributes = if name != js_name {
+        vec![(String::from("js_name"), Some(js_name.to_owned()))]
+    }
+    else {
+        Vec::new()
+    };
+    Ok(wb::StaticDesc::new(attributes, name, type_desc))
+}
+
+/* the javascript module is only needed for the values of constants and the exported names */
+fn parse_js_module(js_path: &path::Path) -> Option<swc_ecma_ast::Module> {
+    match swc::parse_module(js_path) {
+        Ok((js_module, _)) => Some(js_module),
+        Err(error) => {
+            eprintln!("warning: cannot read the javascript module: {}", error);
+            None
+        }
+    }
+}
+
+/* the names that are exported by a javascript module */
+fn process_exports(js_module: &swc_ecma_ast::Module) -> Vec<String> {
+    let mut exports = Vec::new();
+    for item in &js_module.body {
+        match item {
+            swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::ExportDecl(export)) => {
+                match &export.decl {
+                    swc_ecma_ast::Decl::Class(cls_declaration) =>
+                        exports.push(cls_declaration.ident.sym.to_string()),
+                    swc_ecma_ast::Decl::Fn(fn_declaration) =>
+                        exports.push(fn_declaration.ident.sym.to_string()),
+                    swc_ecma_ast::Decl::Var(var_declaration) => {
+                        for declarator in &var_declaration.decls {
+                            if let swc_ecma_ast::Pat::Ident(ident) = &declarator.name {
+                                exports.push(ident.sym.to_string());
+                            }
+                        }
+                    },
+                    _ => {}
+                }
+            },
+            /* e.g., export { CatmullRom, QuadraticBezier, CubicBezier } */
+            swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::ExportNamed(export)) => {
+                for specifier in &export.specifiers {
+                    if let swc_ecma_ast::ExportSpecifier::Named(specifier) = specifier {
+                        let exported = specifier.exported.as_ref().unwrap_or(&specifier.orig);
+                        exports.push(exported.sym.to_string());
+                    }
+                }
+            },
+            _ => {}
+        }
+    }
+    exports
+}
+
+/* the values of the numeric constants that are exported by a javascript module */
+fn process_constants(js_module: &swc_ecma_ast::Module) -> HashMap<String, f64> {
+    let mut constants = HashMap::new();
+    for item in &js_module.body {
+        if let swc_ecma_ast::ModuleItem::ModuleDecl(
+            swc_ecma_ast::ModuleDecl::ExportDecl(export)) = item {
+            if let swc_ecma_ast::Decl::Var(var_declaration) = &export.decl {
+                for declarator in &var_declaration.decls {
+                    if let (swc_ecma_ast::Pat::Ident(ident), Some(init)) = (&declarator.name, &declarator.init) {
+                        let value = match &**init {
+                            swc_ecma_ast::Expr::Lit(swc_ecma_ast::Lit::Num(number)) => Some(number.value),
+                            /* aliases of constants that were exported previously */
+                            swc_ecma_ast::Expr::Ident(alias) => constants.get(&alias.sym.to_string()).copied(),
+                            _ => None,
+                        };
+                        if let Some(value) = value {
+                            constants.insert(ident.sym.to_string(), value);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    constants
+}
+
+/* groups an empty enum with the constants that are declared with its type, e.g.,
+   enum Side {} with const FrontSide: Side, const BackSide: Side, etc. */
+fn process_constant_enum(enum_name: &str,
+                         ts_module: &swc_ecma_ast::Module,
+                         js_constants: &HashMap<String, f64>) -> wb::EnumDesc {
+    let mut enum_variants = Vec::new();
+    for item in &ts_module.body {
+        if let swc_ecma_ast::ModuleItem::ModuleDecl(
+            swc_ecma_ast::ModuleDecl::ExportDecl(export)) = item {
+            if let swc_ecma_ast::Decl::Var(var_declaration) = &export.decl {
+                for declarator in &var_declaration.decls {
+                    if let swc_ecma_ast::Pat::Ident(ident) = &declarator.name {
+                        let enum_type = ident.type_ann
+                            .as_ref()
+                            .and_then(|type_ann| process_type(&type_ann.type_ann).ok());
+                        if enum_type != Some(wb::TypeDesc::Class(enum_name.to_owned())) {
+                            continue;
+                        }
+                        match js_constants.get(&ident.sym.to_string()) {
+                            Some(value) if value.fract() == 0.0 &&
+                                           *value >= 0.0 &&
+                                           *value <= f64::from(u32::MAX) => {
+                                enum_variants.push((ident.sym.to_string(), *value as u32));
+                            },
+                            _ => {
+                                eprintln!("warning: skipping {}::{}: no value in the javascript module",
+                                          enum_name, ident.sym);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    wb::EnumDesc::new(enum_name.to_owned(), enum_variants)
+}
+
+/* computes the values of the enum members the same way as TypeScript, i.e., members without
+   an initializer take the value of the previous member plus one, starting from zero */
+fn process_enum(enum_declaration: &swc_ecma_ast::TsEnumDecl) -> wb::EnumDesc {
+    let enum_name = enum_declaration.id.sym.to_string();
+    let mut enum_values = HashMap::new();
+    let mut enum_variants = Vec::new();
+    let mut next_value = Some(0.0);
+    for member in &enum_declaration.members {
+        let member_name = match &member.id {
+            swc_ecma_ast::TsEnumMemberId::Ident(ident) => ident.sym.to_string(),
+            swc_ecma_ast::TsEnumMemberId::Str(string) => string.value.to_string(),
+        };
+        let value = match &member.init {
+            Some(init) => evaluate_enum_expression(init, &enum_values),
+            None => next_value,
+        };
+        next_value = value.map(|value| value + 1.0);
+        let valid_name = member_name
+            .chars()
+            .enumerate()
+            .all(|(index, c)| c == '_' || c.is_ascii_alphabetic() || (index > 0 && c.is_ascii_digit()));
+        match value {
+            Some(value) if valid_name &&
+                           value.fract() == 0.0 &&
+                           value >= 0.0 &&
+                           value <= f64::from(u32::MAX) => {
+                enum_variants.push((member_name.clone(), value as u32));
+            },
+            _ => {
+                eprintln!("warning: skipping {}::{}: cannot be represented as u32", enum_name, member_name);
+            }
+        }
+        if let Some(value) = value {
+            enum_values.insert(member_name, value);
+        }
+    }
+    wb::EnumDesc::new(enum_name, enum_variants)
+}
+
+/* evaluates constant enum expressions, bitwise operations are done on 32-bit integers as in JavaScript */
+fn evaluate_enum_expression(expr: &swc_ecma_ast::Expr, values: &HashMap<String, f64>) -> Option<f64> {
+    let to_i32 = |value: f64| value as i64 as i32;
+    let to_u32 = |value: f64| value as i64 as u32;
+    match expr {
+        swc_ecma_ast::Expr::Lit(swc_ecma_ast::Lit::Num(number)) => Some(number.value),
+        swc_ecma_ast::Expr::Paren(paren) => evaluate_enum_expression(&paren.expr, values),
+        swc_ecma_ast::Expr::Ident(ident) => values.get(&ident.sym.to_string()).copied(),
+        swc_ecma_ast::Expr::Unary(unary) => {
+            let arg = evaluate_enum_expression(&unary.arg, values)?;
+            match unary.op {
+                swc_ecma_ast::UnaryOp::Minus => Some(-arg),
+                swc_ecma_ast::UnaryOp::Plus => Some(arg),
+                swc_ecma_ast::UnaryOp::Tilde => Some(f64::from(!to_i32(arg))),
+                _ => None,
+            }
+        },
+        swc_ecma_ast::Expr::Bin(binary) => {
+            let left = evaluate_enum_expression(&binary.left, values)?;
+            let right = evaluate_enum_expression(&binary.right, values)?;
+            match binary.op {
+                swc_ecma_ast::BinaryOp::Add => Some(left + right),
+                swc_ecma_ast::BinaryOp::Sub => Some(left - right),
+                swc_ecma_ast::BinaryOp::Mul => Some(left * right),
+                swc_ecma_ast::BinaryOp::Div => Some(left / right),
+                swc_ecma_ast::BinaryOp::Mod => Some(left % right),
+                swc_ecma_ast::BinaryOp::BitOr => Some(f64::from(to_i32(left) | to_i32(right))),
+                swc_ecma_ast::BinaryOp::BitAnd => Some(f64::from(to_i32(left) & to_i32(right))),
+                swc_ecma_ast::BinaryOp::BitXor => Some(f64::from(to_i32(left) ^ to_i32(right))),
+                swc_ecma_ast::BinaryOp::LShift =>
+                    Some(f64::from(to_i32(left).wrapping_shl(to_u32(right) & 31))),
+                swc_ecma_ast::BinaryOp::RShift =>
+                    Some(f64::from(to_i32(left).wrapping_shr(to_u32(right) & 31))),
+                swc_ecma_ast::BinaryOp::ZeroFillRShift =>
+                    Some(f64::from(to_u32(left).wrapping_shr(to_u32(right) & 31))),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
 }
 
 // This function is doing both scanning of the AST and formatting
 // TODO: Move to the string generation into the wb module
 // TODO: Create some intermediate type such as UseDesc that has a vector of symbols and a path
-fn process_imports(module: &swc_ecma_ast::Module) -> HashMap<String, Vec<String>> {
+/* the path of the Rust module that binds a typescript module, e.g., crate::math::vector_2 */
+fn crate_module(ts_path: &path::Path) -> Option<String> {
+    let ts_module_name = ts_path.file_name()?.to_str()?.strip_suffix(".d.ts")?;
+    let mut rs_module = vec![String::from("crate")];
+    for part in ts_path.parent()?.strip_prefix("threejs").ok()? {
+        rs_module.push(part.to_str()?.to_owned());
+    }
+    rs_module.push(ts_module_name.to_snake_case());
+    Some(rs_module.join("::"))
+}
+
+/* imports the symbols from the modules that bind them, which are not necessarily the modules that
+   export them from typescript, e.g., materials/Materials.d.ts re-exports the materials, and imports
+   the types that are referred to without an import, e.g., by the members inherited from an interface
+   of another module. the types that no module binds, e.g., deprecated or non-exported interfaces,
+   are bound as js_sys::Object and the classes no longer extend them */
+fn process_bound_imports(mod_desc: &mut wb::ModuleDesc,
+                         rs_module: &str,
+                         imports: HashMap<String, Vec<String>>,
+                         mod_symbols: &[String],
+                         bound_symbols: &HashMap<String, String>) -> HashMap<String, Vec<String>> {
+    let mut bound_imports : HashMap<String, Vec<String>> = HashMap::new();
+    let mut available : BTreeSet<String> = mod_symbols.iter().cloned().collect();
+    for (path, symbols) in imports {
+        if !path.starts_with("crate::") {
+            bound_imports.entry(path).or_default().extend(symbols);
+            continue;
+        }
+        for symbol in symbols {
+            let (imported, local) = symbol.split_once(" as ").unwrap_or((&symbol, &symbol));
+            match bound_symbols.get(imported) {
+                Some(bound_module) if bound_module != rs_module => {
+                    available.insert(local.to_owned());
+                    bound_imports.entry(bound_module.clone()).or_default().push(symbol.clone());
+                },
+                _ => {},
+            }
+        }
+    }
+    /* resolves a referenced type to an available symbol or imports it, false if it is not bound */
+    let mut resolve = |identifier: &str| {
+        if available.contains(identifier) || identifier.contains("::") {
+            return true;
+        }
+        match bound_symbols.get(identifier) {
+            Some(bound_module) if bound_module != rs_module => {
+                available.insert(identifier.to_owned());
+                bound_imports.entry(bound_module.clone()).or_default().push(identifier.to_owned());
+                true
+            },
+            _ => false,
+        }
+    };
+    mod_desc.visit_types_mut(&mut |type_desc| {
+        if type_desc.dom_feature().is_some() {
+            return;
+        }
+        if let wb::TypeDesc::Class(identifier) | wb::TypeDesc::Generic(identifier, _) = type_desc {
+            if !resolve(identifier) {
+                *type_desc = wb::TypeDesc::Object(Vec::new());
+            }
+        }
+    });
+    for class in mod_desc.classes.iter_mut().chain(mod_desc.interfaces.iter_mut()).chain(mod_desc.intersections.iter_mut()) {
+        class.attributes.retain(|(attribute, value)| match (attribute.as_str(), value) {
+            ("extends", Some(super_class)) => resolve(super_class),
+            _ => true,
+        });
+    }
+    bound_imports
+}
+
+fn process_imports(module: &swc_ecma_ast::Module, module_dir: &path::Path) -> HashMap<String, Vec<String>> {
     /* get imports */
     let mut imports = Vec::new();
     for item in &module.body {
@@ -558,12 +2772,15 @@ fn process_imports(module: &swc_ecma_ast::Module) -> HashMap<String, Vec<String>
                 let mut symbols = Vec::new();
                 for import_specifier in &import.specifiers {
                     if let swc_ecma_ast::ImportSpecifier::Named(named_import_specifier) = import_specifier {
-                        symbols.push(named_import_specifier.local.sym.as_ref());
+                        let local = named_import_specifier.local.sym.to_string();
+                        match &named_import_specifier.imported {
+                            Some(imported) if imported.sym != named_import_specifier.local.sym => {
+                                symbols.push(format!("{} as {}", imported.sym, local));
+                            },
+                            _ => symbols.push(local),
+                        }
                     }
                 }
-                if symbols.len() != 1 {
-                    eprintln!("warning: multiple symbols for imports unhandled");
-                }
                 let source : &str = &import.src.value;
                 imports.push((source, symbols));
             }
@@ -572,32 +2789,246 @@ fn process_imports(module: &swc_ecma_ast::Module) -> HashMap<String, Vec<String>
     /* map for grouping the imports together */
     let mut imports_grouped: HashMap<String, Vec<String>> =
         HashMap::with_capacity(imports.len());
-    /* group and convert import paths */
-    for mut import in imports {
-        if let Some(symbol) = import.1.pop() {
-            let path = import.0.split('/').fold(String::new(), |path, part| {
-                match part {
-                    "." => format!("{}::self", path),
-                    ".." => format!("{}::super", path),
-                    _ => format!("{}::{}", path, part)
-                }
-            });
-            let path = path.replace("self::super", "super");
-            let path = path.trim_start_matches(':');
-            let path_parts : Vec<&str> =
-                path.rsplitn(2, "::")
-                    .collect();
-            if let Some((path_symbol, path)) = path_parts.split_first() {
-                if *path_symbol == symbol {
-                    if let Some(path) = path.first() {
-                        imports_grouped.entry((*path).to_owned())
-                                       .and_modify(|symbols| {
-                                           symbols.push(symbol.to_owned())
-                                       }).or_insert(vec![symbol.to_owned()]);
-                    }
-                }
+    /* resolve the import paths relative to the root of the crate */
+    for import in imports {
+        let mut path : Vec<String> = module_dir
+            .iter()
+            .filter_map(|part| part.to_str())
+            .map(str::to_owned)
+            .collect();
+        let mut resolved = true;
+        for part in import.0.split('/') {
+            match part {
+                "." | "" => {},
+                ".." => resolved &= path.pop().is_some(),
+                _ => path.push(part.to_owned())
+            }
+        }
+        /* the last part of the path is the module, which is named in snake case */
+        match path.pop() {
+            Some(module) if resolved => path.push(module.to_snake_case()),
+            _ => {
+                eprintln!("warning: cannot resolve import {}", import.0);
+                continue;
             }
         }
+        path.insert(0, String::from("crate"));
+        imports_grouped.entry(path.join("::"))
+                       .or_default()
+                       .extend(import.1);
     }
     imports_grouped
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overrides(keys: &[&str]) -> Result<Overrides<String>, String> {
+        Overrides::try_from(keys
+            .iter()
+            .map(|key| (key.to_string(), key.to_string()))
+            .collect::<HashMap<_, _>>())
+    }
+
+    fn method(name: &str, arguments: &[(&str, wb::TypeDesc)]) -> wb::FunctionDesc {
+        let mut fn_arguments = vec![(String::from("this"), wb::ParamDesc::new(wb::TypeDesc::This, true, false))];
+        fn_arguments.extend(arguments
+            .iter()
+            .map(|(arg_name, type_desc)| (arg_name.to_string(), wb::ParamDesc::new(type_desc.clone(), false, false))));
+        wb::FunctionDesc::new(vec![(String::from("method"), None)], name.to_owned(), fn_arguments, None)
+    }
+
+    fn js_name(fn_desc: &wb::FunctionDesc) -> Option<&str> {
+        fn_desc.attributes
+            .iter()
+            .find(|(attribute, _)| attribute == "js_name")
+            .and_then(|(_, value)| value.as_deref())
+    }
+
+    #[test]
+    fn overloads_that_differ_in_one_type_are_merged_into_a_union() {
+        let mut methods = vec![
+            method("set", &[("color", wb::TypeDesc::Class(String::from("Color")))]),
+            method("set", &[("color", wb::TypeDesc::Number)]),
+            method("set", &[("color", wb::TypeDesc::String)]),
+        ];
+        process_overloads(&mut methods);
+        assert_eq!(methods.len(), 1);
+        assert_eq!(methods[0].name, "set");
+        assert_eq!(methods[0].arguments[1].1.type_desc, wb::TypeDesc::Union(vec![
+            wb::TypeDesc::Class(String::from("Color")),
+            wb::TypeDesc::Number,
+            wb::TypeDesc::String,
+        ]));
+    }
+
+    #[test]
+    fn overloads_with_the_same_arity_are_named_by_their_types() {
+        let mut methods = vec![
+            method("to_array", &[("array", wb::TypeDesc::Array(Box::new(wb::TypeDesc::Number)))]),
+            method("to_array", &[("xyz", wb::TypeDesc::Builtin(String::from("ArrayLike")))]),
+        ];
+        process_overloads(&mut methods);
+        let names : Vec<&str> = methods.iter().map(|method| method.name.as_str()).collect();
+        assert_eq!(names, vec!["to_array_with_f64_array", "to_array_with_array_like"]);
+        assert!(methods.iter().all(|method| js_name(method) == Some("to_array")));
+    }
+
+    #[test]
+    fn overloads_with_different_arities_are_named_by_their_arity() {
+        let number = |name: &'static str| (name, wb::TypeDesc::Number);
+        let mut methods = vec![
+            method("make_perspective", &[number("left"), number("right"), number("top"),
+                                         number("bottom"), number("near"), number("far")]),
+            method("make_perspective", &[number("fov"), number("aspect"), number("near"), number("far")]),
+        ];
+        process_overloads(&mut methods);
+        /* the overload with the fewest arguments keeps its name */
+        assert_eq!(methods[0].name, "make_perspective_with_6_arguments");
+        assert_eq!(js_name(&methods[0]), Some("make_perspective"));
+        assert_eq!(methods[1].name, "make_perspective");
+        assert_eq!(js_name(&methods[1]), None);
+    }
+
+    #[test]
+    fn overloads_that_cannot_be_told_apart_are_numbered() {
+        let mut methods = vec![
+            method("apply", &[("matrix", wb::TypeDesc::Class(String::from("Matrix4")))]),
+            method("apply", &[("quaternion", wb::TypeDesc::Class(String::from("Matrix4")))]),
+        ];
+        process_overloads(&mut methods);
+        let names : Vec<&str> = methods.iter().map(|method| method.name.as_str()).collect();
+        assert_eq!(names, vec!["apply_1", "apply_2"]);
+    }
+
+    #[test]
+    fn names_take_precedence_over_globs() {
+        let overrides = overrides(&["*Attribute", "BufferAttribute"]).unwrap();
+        assert_eq!(overrides.get("BufferAttribute").map(String::as_str), Some("BufferAttribute"));
+        assert_eq!(overrides.get("InterleavedBufferAttribute").map(String::as_str), Some("*Attribute"));
+        assert_eq!(overrides.get("Geometry"), None);
+    }
+
+    #[test]
+    fn longer_globs_take_precedence_over_shorter_globs() {
+        let overrides = overrides(&["*Attribute", "*BufferAttribute", "Float??BufferAttribute"]).unwrap();
+        assert_eq!(overrides.get("Float32BufferAttribute").map(String::as_str), Some("Float??BufferAttribute"));
+        assert_eq!(overrides.get("Int8BufferAttribute").map(String::as_str), Some("*BufferAttribute"));
+        assert_eq!(overrides.get("GLBufferAttribute").map(String::as_str), Some("*BufferAttribute"));
+        assert_eq!(overrides.get("MorphAttribute").map(String::as_str), Some("*Attribute"));
+        /* ? matches exactly one character */
+        assert_eq!(overrides.get("Float8BufferAttribute").map(String::as_str), Some("*BufferAttribute"));
+    }
+
+    #[test]
+    fn regexes_match_whole_names() {
+        let overrides = overrides(&[r"/(Int|Uint|Float)\d+(Clamped)?BufferAttribute/"]).unwrap();
+        assert!(overrides.get("Uint8ClampedBufferAttribute").is_some());
+        assert!(overrides.get("Float64BufferAttribute").is_some());
+        assert!(overrides.get("InstancedBufferAttribute").is_none());
+        assert!(overrides.get("Float32BufferAttributes").is_none());
+    }
+
+    #[test]
+    fn invalid_regexes_are_errors() {
+        assert!(Pattern::try_from(String::from("/(Int|Uint/")).is_err());
+        assert!(overrides(&["Color", "/[a-z/"]).is_err());
+        /* a single slash is a name rather than an empty regex */
+        assert!(Pattern::try_from(String::from("/")).unwrap().regex.is_none());
+    }
+
+    #[test]
+    fn precedence_orders_names_then_longer_patterns_then_keys() {
+        let pattern = |key: &str| Pattern::try_from(key.to_owned()).unwrap();
+        let mut patterns = [pattern("*"), pattern("/a.*/"), pattern("*Material"), pattern("b*"), pattern("Material")];
+        patterns.sort_unstable_by(Pattern::precedence);
+        let keys : Vec<&str> = patterns.iter().map(|pattern| pattern.key.as_str()).collect();
+        assert_eq!(keys, vec!["Material", "*Material", "/a.*/", "b*", "*"]);
+    }
+
+    #[test]
+    fn pattern_overrides_are_copied_for_each_name() {
+        let mut overrides = overrides(&["*Material"]).unwrap();
+        overrides.get_mut("MeshBasicMaterial").unwrap().push_str(" for MeshBasicMaterial");
+        assert_eq!(overrides.get_mut("LineBasicMaterial").map(|value| value.as_str()), Some("*Material"));
+        assert_eq!(overrides.get_mut("MeshBasicMaterial").map(|value| value.as_str()),
+                   Some("*Material for MeshBasicMaterial"));
+    }
+
+    fn enum_variants(name: &str, declaration: &str) -> Vec<(String, u32)> {
+        let ts_path = std::env::temp_dir().join(format!("threejs-wasm-bindgen-{}.d.ts", name));
+        fs::write(&ts_path, declaration).unwrap();
+        let (ts_module, _) = swc::parse_module(&ts_path).unwrap();
+        fs::remove_file(&ts_path).unwrap();
+        let enum_desc = ts_module.body
+            .iter()
+            .find_map(|item| match item {
+                swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::ExportDecl(export)) => match &export.decl {
+                    swc_ecma_ast::Decl::TsEnum(enum_declaration) => Some(process_enum(enum_declaration)),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .unwrap();
+        enum_desc.variants
+    }
+
+    fn variants(variants: &[(&str, u32)]) -> Vec<(String, u32)> {
+        variants.iter().map(|(name, value)| (name.to_string(), *value)).collect()
+    }
+
+    #[test]
+    fn enum_members_without_initializers_are_incremented() {
+        assert_eq!(enum_variants("MOUSE", "export enum MOUSE { LEFT, MIDDLE, RIGHT, ROTATE = 0, DOLLY, PAN }"),
+                   variants(&[("LEFT", 0), ("MIDDLE", 1), ("RIGHT", 2), ("ROTATE", 0), ("DOLLY", 1), ("PAN", 2)]));
+    }
+
+    #[test]
+    fn enum_members_take_the_values_of_their_initializers() {
+        assert_eq!(enum_variants("Side", "export enum Side { FrontSide = 0, BackSide = 1, DoubleSide = 2 }"),
+                   variants(&[("FrontSide", 0), ("BackSide", 1), ("DoubleSide", 2)]));
+        assert_eq!(enum_variants("Wrapping", "export enum Wrapping { RepeatWrapping = 1000, ClampToEdgeWrapping }"),
+                   variants(&[("RepeatWrapping", 1000), ("ClampToEdgeWrapping", 1001)]));
+    }
+
+    #[test]
+    fn enum_initializers_are_evaluated_as_32_bit_integers() {
+        assert_eq!(enum_variants("TOUCH", "export enum TOUCH { ROTATE = 1 << 0, PAN = 1 << 1, DOLLY_PAN = ROTATE | PAN, \
+                                           DOLLY_ROTATE = (DOLLY_PAN << 2) | 0x1, ALL = ~0 >>> 0 }"),
+                   variants(&[("ROTATE", 1), ("PAN", 2), ("DOLLY_PAN", 3), ("DOLLY_ROTATE", 13), ("ALL", u32::MAX)]));
+    }
+
+    #[test]
+    fn enum_members_that_are_not_u32_are_skipped() {
+        assert_eq!(enum_variants("Sign", "export enum Sign { Negative = -1, Zero, Half = 0.5, Unknown = Missing + 1 }"),
+                   variants(&[("Zero", 0)]));
+    }
+
+    #[test]
+    fn only_bound_symbols_are_imported() {
+        let mut mod_desc = wb::ModuleDesc::new(Vec::new());
+        mod_desc.classes.push(wb::ClassDesc::new(String::from("Sprite"), Vec::new(), vec![
+            method("setMaterial", &[("material", wb::TypeDesc::Class(String::from("SpriteMaterial")))]),
+            method("setMorphTarget", &[("target", wb::TypeDesc::Class(String::from("MorphTarget")))]),
+            method("setRenderState", &[("state", wb::TypeDesc::Class(String::from("WebGLRenderState")))]),
+            method("setPosition", &[("position", wb::TypeDesc::Class(String::from("Vector3")))]),
+        ]));
+        let imports = vec![
+            (String::from("crate::materials::materials"), vec![String::from("SpriteMaterial")]),
+            (String::from("crate::core::geometry"), vec![String::from("MorphTarget")]),
+        ].into_iter().collect();
+        let bound_symbols = vec![
+            (String::from("Sprite"), String::from("crate::objects::sprite")),
+            (String::from("SpriteMaterial"), String::from("crate::materials::sprite_material")),
+            (String::from("Vector3"), String::from("crate::math::vector_3")),
+        ].into_iter().collect();
+        let imports = process_bound_imports(&mut mod_desc, "crate::objects::sprite", imports,
+                                            &[String::from("Sprite")], &bound_symbols);
+        let mut output = Vec::new();
+        wb::Writer::new(&mut output).write_imports(imports).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(),
+                   "use crate::materials::sprite_material::SpriteMaterial;\nuse crate::math::vector_3::Vector3;\n");
+        assert_eq!(mod_desc.classes[0].methods[2].arguments[1].1.type_desc, wb::TypeDesc::Object(Vec::new()));
+    }
+}
@@ -1,4 +1,4 @@
-use swc_common::{comments, errors::{ColorConfig, Handler}, SourceMap};
+use swc_common::{comments, errors::{ColorConfig, Handler}, Globals, SourceMap};
 use swc_ecma_parser::{lexer::Lexer, Parser, Session, SourceFileInput, Syntax, TsConfig};
 use std::{io, path, sync};
 
@@ -13,20 +13,27 @@ Result<(swc_ecma_ast::Module, swc_common::comments::Comments), io::Error> {
     let session = Session { handler: &handler };
     let source = source_map.load_file(path)?;
     let comments : comments::Comments = Default::default();
+    /* the javascript modules are parsed for the values of their constants */
+    let syntax = if path.extension().is_some_and(|ext| ext == "js") {
+        Syntax::Es(Default::default())
+    }
+    else {
+        Syntax::Typescript(TsConfig {dts: true, ..Default::default()})
+    };
     let lexer = Lexer::new(
         session,
-        Syntax::Typescript(TsConfig {dts: true, ..Default::default()}),
+        syntax,
         Default::default(),
         SourceFileInput::from(&*source),
         Some(&comments),
     );
     let mut parser = Parser::new_from(session, lexer);
-    parser
-        .parse_module()
+    /* parsing some javascript requires the hygiene data of swc */
+    swc_common::GLOBALS.set(&Globals::new(), || parser.parse_module())
         .map_err(|error| {
             io::Error::new(io::ErrorKind::Other,
             format!("{:?}: {}", path.to_str(), error.message()))
         })
-        .and_then(|m| Ok((m, comments)))
+        .map(|m| (m, comments))
 }
 
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use serde::{Serialize, Deserialize};
 use std::convert::TryFrom;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum TypeDesc {
     Any,
@@ -17,10 +17,202 @@ pub enum TypeDesc {
     Array(Box<TypeDesc>),
     Function(Vec<(String, TypeDesc)>, Option<Box<TypeDesc>>),
     Class(String),
+    Generic(String, Vec<TypeDesc>),
     Union(Vec<TypeDesc>),
+    /* a value that has all of the types, which is bound as an extern type that extends them once it is named */
+    Intersection(Vec<TypeDesc>),
+    /* a promise that resolves to a value of the inner type */
+    Promise(Box<TypeDesc>),
+    /* a JavaScript built-in type that is bound by js_sys, e.g., Float32Array */
+    Builtin(String),
+    /* an array with a fixed length, e.g., [number, string] */
+    Tuple(Vec<TypeDesc>),
+    /* a string literal, e.g., 'srgb' */
+    Literal(String),
+    /* a union of string literals, which is bound as an enum once it is named */
+    Literals(Vec<String>),
+    /* an object literal, which is bound as an interface once it is named */
+    Object(Vec<(String, TypeDesc)>),
+    /* an object that maps strings to values, e.g., { [key: string]: number } */
+    Record(Box<TypeDesc>),
+    /* a Rust type that a TypeScript type is mapped to by the overrides, e.g., f32 */
+    Rust(String),
     Unimplemented,
 }
 
+/* the Rust types of numbers and the slices, vectors and typed arrays that bind arrays of them */
+const NUMBER_TYPES: &[(&str, &str, &str, &str)] = &[
+    ("f64", "&[f64]", "Vec<f64>", "js_sys::Float64Array"),
+    ("f32", "&[f32]", "Vec<f32>", "js_sys::Float32Array"),
+    ("i32", "&[i32]", "Vec<i32>", "js_sys::Int32Array"),
+    ("u32", "&[u32]", "Vec<u32>", "js_sys::Uint32Array"),
+    ("i16", "&[i16]", "Vec<i16>", "js_sys::Int16Array"),
+    ("u16", "&[u16]", "Vec<u16>", "js_sys::Uint16Array"),
+    ("i8", "&[i8]", "Vec<i8>", "js_sys::Int8Array"),
+    ("u8", "&[u8]", "Vec<u8>", "js_sys::Uint8Array"),
+];
+
+/* the TypeScript names of the built-in types, their js_sys types and the
+   element types of the typed arrays */
+const BUILTIN_TYPES: &[(&str, &str, Option<&str>)] = &[
+    ("ArrayBuffer", "js_sys::ArrayBuffer", None),
+    ("DataView", "js_sys::DataView", None),
+    ("Function", "js_sys::Function", None),
+    ("RegExp", "js_sys::RegExp", None),
+    /* any typed array or array of numbers */
+    ("ArrayLike", "js_sys::Object", None),
+    ("ArrayBufferView", "js_sys::Object", None),
+    ("Iterable", "js_sys::Object", None),
+    ("TypedArray", "js_sys::Object", None),
+    ("Int8Array", "js_sys::Int8Array", Some("i8")),
+    ("Uint8Array", "js_sys::Uint8Array", Some("u8")),
+    ("Uint8ClampedArray", "js_sys::Uint8ClampedArray", Some("u8")),
+    ("Int16Array", "js_sys::Int16Array", Some("i16")),
+    ("Uint16Array", "js_sys::Uint16Array", Some("u16")),
+    ("Int32Array", "js_sys::Int32Array", Some("i32")),
+    ("Uint32Array", "js_sys::Uint32Array", Some("u32")),
+    ("Float32Array", "js_sys::Float32Array", Some("f32")),
+    ("Float64Array", "js_sys::Float64Array", Some("f64")),
+];
+
+/* the TypeScript names of the DOM and WebGL types and their web_sys types, which
+   are also the names of the web-sys features that enable them */
+const DOM_TYPES: &[(&str, &str)] = &[
+    ("AnalyserNode", "AnalyserNode"),
+    ("AudioBuffer", "AudioBuffer"),
+    ("AudioBufferSourceNode", "AudioBufferSourceNode"),
+    ("AudioContext", "AudioContext"),
+    ("AudioNode", "AudioNode"),
+    ("DistanceModelType", "DistanceModelType"),
+    ("ErrorEvent", "ErrorEvent"),
+    ("GainNode", "GainNode"),
+    ("HTMLCanvasElement", "HtmlCanvasElement"),
+    ("HTMLImageElement", "HtmlImageElement"),
+    ("HTMLMediaElement", "HtmlMediaElement"),
+    ("HTMLVideoElement", "HtmlVideoElement"),
+    ("ImageBitmap", "ImageBitmap"),
+    ("ImageData", "ImageData"),
+    ("MediaStream", "MediaStream"),
+    ("MimeType", "MimeType"),
+    ("MouseEvent", "MouseEvent"),
+    ("OffscreenCanvas", "OffscreenCanvas"),
+    ("PannerNode", "PannerNode"),
+    ("ProgressEvent", "ProgressEvent"),
+    ("WebGL2RenderingContext", "WebGl2RenderingContext"),
+    ("WebGLBuffer", "WebGlBuffer"),
+    ("WebGLFramebuffer", "WebGlFramebuffer"),
+    ("WebGLRenderingContext", "WebGlRenderingContext"),
+    ("WebGLTexture", "WebGlTexture"),
+    ("WebGLUniformLocation", "WebGlUniformLocation"),
+    ("Window", "Window"),
+];
+
+/* the TypeScript typedefs of the WebGL numbers and their Rust number types */
+const GL_TYPES: &[(&str, &str)] = &[
+    ("GLenum", "u32"),
+];
+
+impl TypeDesc {
+    /* DOM types are bound as classes with the names of their web_sys types */
+    pub fn dom(name: &str) -> Option<TypeDesc> {
+        DOM_TYPES
+            .iter()
+            .find(|dom_type| dom_type.0 == name)
+            .map(|dom_type| TypeDesc::Class(dom_type.1.to_owned()))
+    }
+
+    /* WebGL numbers are typedefs rather than DOM types, they are bound as Rust numbers */
+    pub fn gl(name: &str) -> Option<TypeDesc> {
+        GL_TYPES
+            .iter()
+            .find(|gl_type| gl_type.0 == name)
+            .map(|gl_type| TypeDesc::Rust(gl_type.1.to_owned()))
+    }
+
+    /* the web-sys feature that is required by a DOM type */
+    pub fn dom_feature(&self) -> Option<&'static str> {
+        match self {
+            TypeDesc::Class(identifier) => DOM_TYPES
+                .iter()
+                .find(|dom_type| dom_type.1 == identifier)
+                .map(|dom_type| dom_type.1),
+            _ => None,
+        }
+    }
+
+    pub fn builtin(name: &str) -> Option<TypeDesc> {
+        BUILTIN_TYPES
+            .iter()
+            .find(|builtin| builtin.0 == name)
+            .map(|builtin| TypeDesc::Builtin(builtin.0.to_owned()))
+    }
+
+    /* numbers are bound as f64 unless the overrides map them to another Rust number type */
+    fn number_type(&self) -> Option<&'static (&'static str, &'static str, &'static str, &'static str)> {
+        match self {
+            TypeDesc::Number => NUMBER_TYPES.first(),
+            TypeDesc::Rust(identifier) => NUMBER_TYPES
+                .iter()
+                .find(|number_type| number_type.0 == identifier),
+            _ => None,
+        }
+    }
+
+    /* arrays of numbers are passed as slices and returned as vectors */
+    fn number_array(&self) -> Option<&'static (&'static str, &'static str, &'static str, &'static str)> {
+        match self {
+            TypeDesc::Array(inner_type) => inner_type.number_type(),
+            _ => None,
+        }
+    }
+
+    /* the TypeScript name of a type that can be mapped to a Rust type by the overrides */
+    pub fn ts_name(&self) -> Option<&str> {
+        match self {
+            TypeDesc::Any => Some("any"),
+            TypeDesc::Boolean => Some("boolean"),
+            TypeDesc::Number => Some("number"),
+            TypeDesc::String => Some("string"),
+            TypeDesc::Class(identifier) |
+            TypeDesc::Generic(identifier, _) |
+            TypeDesc::Builtin(identifier) => Some(identifier),
+            _ => None,
+        }
+    }
+
+    /* visits the inner types before visiting the type itself */
+    pub fn visit_mut<F: FnMut(&mut TypeDesc)>(&mut self, visitor: &mut F) {
+        match self {
+            TypeDesc::Array(inner_type) |
+            TypeDesc::Promise(inner_type) |
+            TypeDesc::Record(inner_type) => inner_type.visit_mut(visitor),
+            TypeDesc::Function(parameters, return_type) => {
+                for (_, parameter) in parameters.iter_mut() {
+                    parameter.visit_mut(visitor);
+                }
+                if let Some(return_type) = return_type {
+                    return_type.visit_mut(visitor);
+                }
+            },
+            TypeDesc::Generic(_, types) |
+            TypeDesc::Union(types) |
+            TypeDesc::Intersection(types) |
+            TypeDesc::Tuple(types) => {
+                for inner_type in types.iter_mut() {
+                    inner_type.visit_mut(visitor);
+                }
+            },
+            TypeDesc::Object(properties) => {
+                for (_, property) in properties.iter_mut() {
+                    property.visit_mut(visitor);
+                }
+            },
+            _ => {}
+        }
+        visitor(self);
+    }
+}
+
 impl<'a> TryFrom<&'a TypeDesc> for &'a str {
     type Error = &'static str;
 
@@ -39,10 +231,10 @@ impl<'a> TryFrom<&'a TypeDesc> for &'a str {
             TypeDesc::Undefined => Err("cannot convert from undefined"),
             TypeDesc::Unimplemented => Err("type not implemented"),
             TypeDesc::Array(inner_type) => {
-                if let TypeDesc::Number = **inner_type {
+                if let Some(number_type) = inner_type.number_type() {
                     /* it seems more efficient to just pass a slice here */
-                    /* the glue code wraps the wasm memory buffer in a Float64Array for us */
-                    Ok("&[f64]")
+                    /* the glue code wraps the wasm memory buffer in a typed array for us */
+                    Ok(number_type.1)
                 }
                 else {
                     Ok("js_sys::Array")
@@ -50,17 +242,109 @@ impl<'a> TryFrom<&'a TypeDesc> for &'a str {
             },
             TypeDesc::Function(_, _) => Ok("js_sys::Function"),
             TypeDesc::Class(identifier) => Ok(&identifier),
+            /* generics without an instance are bound as their unparameterized class */
+            TypeDesc::Generic(identifier, _) => Ok(identifier),
             TypeDesc::Union(_) => Err("cannot convert from union"),
+            /* intersections that have not been named are only accessible as values */
+            TypeDesc::Intersection(_) => Ok("JsValue"),
+            TypeDesc::Promise(_) => Ok("js_sys::Promise"),
+            TypeDesc::Builtin(identifier) => BUILTIN_TYPES
+                .iter()
+                .find(|builtin| builtin.0 == identifier)
+                .map(|builtin| builtin.1)
+                .ok_or("cannot convert unknown built-in type"),
+            TypeDesc::Tuple(_) => Ok("js_sys::Array"),
+            /* literals that have not been named as enums are bound as strings */
+            TypeDesc::Literal(_) | TypeDesc::Literals(_) => Ok("String"),
+            TypeDesc::Object(_) | TypeDesc::Record(_) => Ok("js_sys::Object"),
+            TypeDesc::Rust(identifier) => Ok(identifier),
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+/* a Rust enum with a variant for each type in a union, e.g., Color | string */
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnionDesc {
+    pub name: String,
+    pub variants: Vec<(String, TypeDesc)>,
+}
+
+impl UnionDesc {
+    pub fn new(types: &[TypeDesc]) -> Result<UnionDesc, &'static str> {
+        let mut variants : Vec<(String, TypeDesc)> = Vec::with_capacity(types.len());
+        for type_desc in types {
+            let variant = UnionDesc::variant_name(type_desc)?;
+            /* types that are bound by the same Rust type only need one variant */
+            let variant_type = UnionDesc::variant_type(type_desc)?;
+            let duplicate = variants.iter().any(|(name, other)| {
+                name == &variant || UnionDesc::variant_type(other) == Ok(variant_type)
+            });
+            if !duplicate {
+                variants.push((variant, type_desc.clone()));
+            }
+        }
+        if variants.is_empty() {
+            return Err("cannot convert from an empty union");
+        }
+        let name = variants
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>()
+            .join("Or");
+        Ok(UnionDesc {
+            name,
+            variants,
+        })
+    }
+
+    fn variant_name(type_desc: &TypeDesc) -> Result<String, &'static str> {
+        match type_desc {
+            TypeDesc::Any => Ok(String::from("JsValue")),
+            TypeDesc::Boolean => Ok(String::from("Bool")),
+            TypeDesc::Number => Ok(String::from("Number")),
+            TypeDesc::String => Ok(String::from("String")),
+            TypeDesc::Array(inner_type) =>
+                Ok(format!("{}Array", UnionDesc::variant_name(inner_type)?)),
+            TypeDesc::Function(_, _) => Ok(String::from("Function")),
+            TypeDesc::Promise(_) => Ok(String::from("Promise")),
+            TypeDesc::Tuple(_) => Ok(String::from("Tuple")),
+            TypeDesc::Literal(_) | TypeDesc::Literals(_) => Ok(String::from("String")),
+            TypeDesc::Object(_) | TypeDesc::Record(_) => Ok(String::from("Object")),
+            TypeDesc::Builtin(identifier) |
+            TypeDesc::Class(identifier) |
+            TypeDesc::Generic(identifier, _) => Ok(identifier.clone()),
+            /* the variant is named after the last segment of the path, e.g., F32 for f32 */
+            TypeDesc::Rust(identifier) => {
+                let segment = identifier.rsplit("::").next().unwrap_or(identifier);
+                let mut characters = segment.chars();
+                Ok(characters
+                    .next()
+                    .map(|first| first.to_uppercase().chain(characters).collect())
+                    .unwrap_or_default())
+            },
+            _ => Err("cannot convert variant of union"),
+        }
+    }
+
+    fn variant_type(type_desc: &TypeDesc) -> Result<&str, &'static str> {
+        match type_desc.number_array() {
+            /* the variant owns its values, the slice is only used for the conversion */
+            Some(number_type) => Ok(number_type.2),
+            None => <&str>::try_from(type_desc),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
 pub struct ParamDesc {
     #[serde(rename = "type")]
     pub type_desc: TypeDesc,
     pub reference: bool,
     pub optional: bool,
+    /* only valid for the last argument of a function */
+    #[serde(default)]
+    pub variadic: bool,
 }
 
 impl ParamDesc {
@@ -71,11 +355,22 @@ impl ParamDesc {
             type_desc: type_desc,
             reference: reference,
             optional: optional,
+            variadic: false,
+        }
+    }
+
+    fn format(&self, rs_type: &str) -> String {
+        match (self.reference, self.optional) {
+            (false, false) => rs_type.to_owned(),
+            (false, true) => format!("Option<{}>", rs_type),
+            (true, false) => format!("&{}", rs_type),
+            (true, true) => format!("&Option<{}>", rs_type),
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
 pub struct FunctionDesc {
     #[serde(default)]
     pub attributes: Vec<(String, Option<String>)>,
@@ -85,6 +380,8 @@ pub struct FunctionDesc {
     pub arguments: Vec<(String, ParamDesc)>,
     #[serde(default)]
     pub returns: Option<ParamDesc>,
+    #[serde(default, rename = "static")]
+    pub is_static: bool,
 }
 
 impl FunctionDesc {
@@ -97,17 +394,84 @@ impl FunctionDesc {
             name: name,
             arguments: arguments,
             returns: returns,
+            is_static: false,
         }
     }
+
+    pub fn visit_types_mut<F: FnMut(&mut TypeDesc)>(&mut self, visitor: &mut F) {
+        for (_, argument) in self.arguments.iter_mut() {
+            argument.type_desc.visit_mut(visitor);
+        }
+        if let Some(returns) = &mut self.returns {
+            returns.type_desc.visit_mut(visitor);
+        }
+    }
+
+    fn has_union_arguments(&self) -> bool {
+        self.arguments
+            .iter()
+            .any(|(_, param)| matches!(param.type_desc, TypeDesc::Union(_)))
+    }
+
+    fn returns_promise(&self) -> bool {
+        self.returns
+            .as_ref()
+            .is_some_and(|returns| matches!(returns.type_desc, TypeDesc::Promise(_)))
+    }
+
+    /* functions that take unions or return promises are called through a wrapper */
+    fn has_wrapper(&self) -> bool {
+        self.has_union_arguments() || self.returns_promise()
+    }
+
+    /* the binding that takes the union arguments as JsValue and returns the promise, the wrapper
+       with the original name converts the union arguments and awaits the promise */
+    fn lower(&self) -> FunctionDesc {
+        let mut binding = self.clone();
+        binding.name = if self.has_union_arguments() {
+            format!("{}_with_js_value", self.name)
+        }
+        else {
+            format!("{}_promise", self.name)
+        };
+        let js_name_set = binding.attributes
+            .iter()
+            .any(|(attribute, _)| attribute == "js_name" || attribute == "constructor");
+        let mut is_setter = false;
+        for attribute in binding.attributes.iter_mut() {
+            /* the property can no longer be inferred from the name of the setter */
+            if attribute.0 == "setter" {
+                is_setter = true;
+                if attribute.1.is_none() {
+                    attribute.1 = self.name.strip_prefix("set_").map(str::to_owned);
+                }
+            }
+        }
+        if !js_name_set && !is_setter {
+            binding.attributes.push((String::from("js_name"), Some(self.name.clone())));
+        }
+        for (_, param) in binding.arguments.iter_mut() {
+            if let TypeDesc::Union(_) = param.type_desc {
+                *param = ParamDesc::new(TypeDesc::Any, true, false);
+            }
+        }
+        binding
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ClassDesc {
     pub name: String,
     #[serde(default)]
     pub attributes: Vec<(String, Option<String>)>,
     #[serde(default)]
-    pub methods: Vec<FunctionDesc>
+    pub methods: Vec<FunctionDesc>,
+    /* the names of the generic type parameters, e.g., T in Curve<T> */
+    #[serde(default)]
+    pub type_params: Vec<String>,
+    /* the type arguments of a generic super class, e.g., Vector3 in Curve<Vector3> */
+    #[serde(default)]
+    pub super_type_args: Vec<TypeDesc>,
 }
 
 impl ClassDesc {
@@ -115,32 +479,223 @@ impl ClassDesc {
                attributes: Vec<(String, Option<String>)>,
                methods: Vec<FunctionDesc>) -> ClassDesc {
         ClassDesc {
-            name: name,
-            attributes: attributes,
-            methods: methods
+            name,
+            attributes,
+            methods,
+            type_params: Vec::new(),
+            super_type_args: Vec::new(),
+        }
+    }
+
+    /* the load method of a class that extends Loader, which passes the loaded value to its second argument */
+    pub fn loader(&self) -> Option<&FunctionDesc> {
+        let extends_loader = self.attributes
+            .iter()
+            .any(|attribute| attribute.0 == "extends" && attribute.1.as_deref() == Some("Loader"));
+        if !extends_loader {
+            return None;
+        }
+        self.methods
+            .iter()
+            .find(|method| method.name == "load" && !method.is_static && matches!(
+                method.arguments.get(2),
+                Some((_, ParamDesc { type_desc: TypeDesc::Function(_, _), .. }))))
+    }
+
+    pub fn visit_types_mut<F: FnMut(&mut TypeDesc)>(&mut self, visitor: &mut F) {
+        for method in self.methods.iter_mut() {
+            method.visit_types_mut(visitor);
+        }
+        for super_type_arg in self.super_type_args.iter_mut() {
+            super_type_arg.visit_mut(visitor);
+        }
+    }
+}
+
+/* a C-style enum whose variants have the values of the JavaScript constants */
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EnumDesc {
+    pub name: String,
+    #[serde(default)]
+    pub variants: Vec<(String, u32)>,
+}
+
+impl EnumDesc {
+    pub fn new(name: String,
+               variants: Vec<(String, u32)>) -> EnumDesc {
+        EnumDesc {
+            name,
+            variants,
+        }
+    }
+}
+
+/* an enum whose variants are the string literals of a union, e.g., 'highp' | 'mediump' | 'lowp' */
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StringEnumDesc {
+    pub name: String,
+    #[serde(default)]
+    pub variants: Vec<(String, String)>,
+}
+
+impl StringEnumDesc {
+    pub fn new(name: String,
+               variants: Vec<(String, String)>) -> StringEnumDesc {
+        StringEnumDesc {
+            name,
+            variants,
+        }
+    }
+}
+
+/* the members of a TypeScript namespace, which are bound as free functions */
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NamespaceDesc {
+    pub name: String,
+    /* the Rust module for the namespace, if it is not bound by the module of its file */
+    #[serde(default)]
+    pub module: Option<String>,
+    #[serde(default)]
+    pub functions: Vec<FunctionDesc>,
+}
+
+impl NamespaceDesc {
+    pub fn new(name: String,
+               functions: Vec<FunctionDesc>) -> NamespaceDesc {
+        NamespaceDesc {
+            name,
+            module: None,
+            functions,
         }
     }
 }
 
+/* a value exported by a module, which is read once on first access */
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StaticDesc {
+    #[serde(default)]
+    pub attributes: Vec<(String, Option<String>)>,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_desc: TypeDesc,
+}
+
+impl StaticDesc {
+    pub fn new(attributes: Vec<(String, Option<String>)>,
+               name: String,
+               type_desc: TypeDesc) -> StaticDesc {
+        StaticDesc {
+            attributes,
+            name,
+            type_desc,
+        }
+    }
+
+    /* statics are accessed by reference, so primitives are bound as their JavaScript wrappers */
+    pub fn static_type(type_desc: &TypeDesc) -> Result<&str, &'static str> {
+        match type_desc {
+            TypeDesc::Boolean => Ok("js_sys::Boolean"),
+            TypeDesc::Number => Ok("js_sys::Number"),
+            TypeDesc::Rust(_) if type_desc.number_type().is_some() => Ok("js_sys::Number"),
+            TypeDesc::String | TypeDesc::Literal(_) | TypeDesc::Literals(_) => Ok("js_sys::JsString"),
+            TypeDesc::Array(_) => Ok("js_sys::Array"),
+            TypeDesc::Union(_) => Ok("JsValue"),
+            _ => <&str>::try_from(type_desc),
+        }
+    }
+}
+
+/* the declarations of a module, which are imported from the same JavaScript module */
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ModuleDesc {
     pub attributes: Vec<(String, Option<String>)>,
-    pub class: ClassDesc,
+    #[serde(default)]
+    pub classes: Vec<ClassDesc>,
+    /* interfaces only exist in TypeScript and are not imported from the module */
+    #[serde(default)]
+    pub interfaces: Vec<ClassDesc>,
+    /* the extern types for intersections with classes, which cannot be constructed */
+    #[serde(default)]
+    pub intersections: Vec<ClassDesc>,
+    #[serde(default)]
+    pub enums: Vec<EnumDesc>,
+    #[serde(default)]
+    pub string_enums: Vec<StringEnumDesc>,
+    #[serde(default)]
+    pub namespaces: Vec<NamespaceDesc>,
+    /* the free functions and values that are exported by the module */
+    #[serde(default)]
+    pub functions: Vec<FunctionDesc>,
+    #[serde(default)]
+    pub statics: Vec<StaticDesc>,
 }
 
 impl ModuleDesc {
-    pub fn new(attributes: Vec<(String, Option<String>)>,
-               class: ClassDesc) -> ModuleDesc {
+    pub fn new(attributes: Vec<(String, Option<String>)>) -> ModuleDesc {
         ModuleDesc {
-            attributes: attributes,
-            class: class
+            attributes,
+            classes: Vec::new(),
+            interfaces: Vec::new(),
+            intersections: Vec::new(),
+            enums: Vec::new(),
+            string_enums: Vec::new(),
+            namespaces: Vec::new(),
+            functions: Vec::new(),
+            statics: Vec::new(),
+        }
+    }
+
+    pub fn visit_types_mut<F: FnMut(&mut TypeDesc)>(&mut self, visitor: &mut F) {
+        let classes = self.classes
+            .iter_mut()
+            .chain(self.interfaces.iter_mut())
+            .chain(self.intersections.iter_mut());
+        for class in classes {
+            class.visit_types_mut(visitor);
+        }
+        let namespace_functions = self.namespaces
+            .iter_mut()
+            .flat_map(|namespace| namespace.functions.iter_mut());
+        for function in self.functions.iter_mut().chain(namespace_functions) {
+            function.visit_types_mut(visitor);
+        }
+        for value in self.statics.iter_mut() {
+            value.type_desc.visit_mut(visitor);
+        }
+    }
+
+    /* visits the methods of the classes and the free and namespaced functions */
+    pub fn visit_functions_mut<F: FnMut(&mut FunctionDesc)>(&mut self, visitor: &mut F) {
+        let methods = self.classes
+            .iter_mut()
+            .chain(self.interfaces.iter_mut())
+            .chain(self.intersections.iter_mut())
+            .flat_map(|class| class.methods.iter_mut());
+        let namespace_functions = self.namespaces
+            .iter_mut()
+            .flat_map(|namespace| namespace.functions.iter_mut());
+        for function in methods.chain(self.functions.iter_mut()).chain(namespace_functions) {
+            visitor(function);
         }
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty() &&
+        self.interfaces.is_empty() &&
+        self.intersections.is_empty() &&
+        self.enums.is_empty() &&
+        self.string_enums.is_empty() &&
+        self.namespaces.is_empty() &&
+        self.functions.is_empty() &&
+        self.statics.is_empty()
+    }
 }
 
 pub struct Writer<W: Write> {
     indentation: usize,
     output: BufWriter<W>,
+    /* the names of the enums that have been written for unions */
+    unions: Vec<String>,
 }
 
 impl<W> Writer<W> where W: Write {
@@ -148,6 +703,7 @@ impl<W> Writer<W> where W: Write {
         Writer {
             indentation: 0,
             output: BufWriter::new(w),
+            unions: Vec::new(),
         }
     }
 
@@ -184,79 +740,744 @@ impl<W> Writer<W> where W: Write {
     }
 
     pub fn write_function(&mut self, function: &FunctionDesc, class_name: Option<&str>) -> io::Result<()> {
-        self.write_export(&function.attributes)?;
+        let mut attributes = Vec::with_capacity(function.attributes.len() + 2);
+        if function.is_static {
+            /* static members are bound to the class */
+            let class_name = class_name.ok_or_else(||
+                io::Error::other("write_function requires the class for static methods"))?;
+            attributes.push((String::from("static_method_of"), Some(class_name.to_owned())));
+        }
+        attributes.extend(function.attributes.iter().cloned());
+        /* variadic functions collect their remaining arguments into the last parameter */
+        if function.arguments.iter().rev().skip(1).any(|arg| arg.1.variadic) {
+            return Err(io::Error::other(format!("Only the last parameter of {} can be variadic",
+                                                function.name)));
+        }
+        let variadic = function.arguments.last().is_some_and(|arg| arg.1.variadic);
+        if variadic && !attributes.iter().any(|attr| attr.0 == "variadic") {
+            attributes.push((String::from("variadic"), None));
+        }
+        self.write_export(&attributes)?;
         let arguments = function.arguments
             .iter()
-            .try_fold::<_, _, io::Result<String>>(String::new(), |mut res, arg| {
-            if !res.is_empty() {
-                res.push_str(", ");
-            }
-            let rs_type = if let TypeDesc::This = arg.1.type_desc {
-                class_name.ok_or(
-                    io::Error::new(io::ErrorKind::Other,
-                                   "write_function requires the class for methods"))
-            }
-            else {
-                <&str>::try_from(&arg.1.type_desc)
+            .map(|(arg_name, param)| {
+                Writer::<W>::format_argument(param, class_name)
+                    .map(|rs_type| format!("{}: {}", arg_name, rs_type))
                     .map_err(|_e| io::Error::new(io::ErrorKind::Other, 
                                                  format!("Cannot convert parameter {} of {}",
-                                                         arg.0,
+                                                         arg_name,
                                                          function.name)))
-            }?;
-            let arg = match (arg.1.reference, arg.1.optional) {
-                (false, false) => format!("{}: {}", arg.0, rs_type),
-                (false, true) => format!("{}: Option<{}>", arg.0, rs_type),
-                (true, false) => format!("{}: &{}", arg.0, rs_type),
-                (true, true) => format!("{}: &Option<{}>", arg.0, rs_type),
-            };
-            res.push_str(&arg);
-            Ok(res)
-        })?;
+            })
+            .collect::<io::Result<Vec<_>>>()?
+            .join(", ");
         let mut fn_str = format!("pub fn {}({})", function.name, arguments);
+        fn_str.push_str(&Writer::<W>::format_returns(function, class_name)?);
+        fn_str.push(';');
+        self.write_line(&fn_str)
+    }
+
+    fn format_type<'a>(type_desc: &'a TypeDesc, class_name: Option<&'a str>) -> io::Result<&'a str> {
+        if let TypeDesc::This = type_desc {
+            class_name.ok_or(io::Error::other("write_function requires the class for methods"))
+        }
+        else {
+            <&str>::try_from(type_desc)
+                .map_err(io::Error::other)
+        }
+    }
+
+    /* callbacks are passed as closures with the signature of the function type */
+    fn format_argument(param: &ParamDesc, class_name: Option<&str>) -> io::Result<String> {
+        if let TypeDesc::Function(parameters, returns) = &param.type_desc {
+            if let Ok(closure) = Writer::<W>::format_closure(parameters, returns.as_deref(), class_name) {
+                return Ok(match param.optional {
+                    true => format!("Option<&{}>", closure),
+                    false => format!("&{}", closure),
+                });
+            }
+        }
+        Writer::<W>::format_type(&param.type_desc, class_name)
+            .map(|rs_type| param.format(rs_type))
+    }
+
+    fn format_closure(parameters: &[(String, TypeDesc)],
+                      returns: Option<&TypeDesc>,
+                      class_name: Option<&str>) -> io::Result<String> {
+        let arguments = parameters
+            .iter()
+            .map(|(_, type_desc)| Writer::<W>::format_callback_type(type_desc, class_name))
+            .collect::<io::Result<Vec<_>>>()?;
+        let returns = match returns {
+            None | Some(TypeDesc::Void) | Some(TypeDesc::Undefined) => String::new(),
+            Some(type_desc) => format!(" -> {}", Writer::<W>::format_callback_type(type_desc, class_name)?),
+        };
+        Ok(format!("Closure<dyn FnMut({}){}>", arguments.join(", "), returns))
+    }
+
+    /* the values that are passed to and returned from callbacks are owned */
+    fn format_callback_type(type_desc: &TypeDesc, class_name: Option<&str>) -> io::Result<String> {
+        if let Some(number_type) = type_desc.number_array() {
+            return Ok(number_type.2.to_owned());
+        }
+        match type_desc {
+            TypeDesc::Union(types) => {
+                let defined_types = types
+                    .iter()
+                    .filter(|type_desc| !matches!(type_desc, TypeDesc::Null | TypeDesc::Undefined))
+                    .collect::<Vec<_>>();
+                let nullable = types.len() != defined_types.len();
+                match &defined_types[..] {
+                    [type_desc] if nullable => Writer::<W>::format_callback_type(type_desc, class_name)
+                        .map(|rs_type| format!("Option<{}>", rs_type)),
+                    [type_desc] => Writer::<W>::format_callback_type(type_desc, class_name),
+                    _ => Ok(String::from("JsValue")),
+                }
+            },
+            type_desc => Writer::<W>::format_type(type_desc, class_name).map(str::to_owned),
+        }
+    }
+
+    fn format_returns(function: &FunctionDesc, class_name: Option<&str>) -> io::Result<String> {
         if let Some(rt) = &function.returns {
-            let rs_type = match rt.type_desc {
+            let rs_type = match rt.type_desc.number_array() {
+                /* slices cannot be returned from JavaScript, the numbers are copied into a vector */
+                Some(number_type) => number_type.2,
+                None => Writer::<W>::format_type(&rt.type_desc, class_name)
+                    .map_err(|_e| io::Error::other(format!("Cannot convert return type of {}",
+                                                           function.name)))?
+            };
+            Ok(format!(" -> {}", rt.format(rs_type)))
+        }
+        else {
+            Ok(String::new())
+        }
+    }
+
+    /* writes a function with the original name that converts its union arguments into JsValue
+       and awaits the promise that it returns */
+    pub fn write_wrapper(&mut self, function: &FunctionDesc, class_name: Option<&str>) -> io::Result<()> {
+        let binding = function.lower();
+        let mut parameters = Vec::with_capacity(function.arguments.len());
+        let mut arguments = Vec::with_capacity(function.arguments.len());
+        /* free functions call the binding directly */
+        let mut receiver = String::from(if class_name.is_some() { "Self::" } else { "" });
+        for (arg_name, param) in &function.arguments {
+            match &param.type_desc {
                 TypeDesc::This => {
-                    class_name.ok_or(
-                        io::Error::new(io::ErrorKind::Other,
-                                       "write_function requires the class for methods"))?
+                    parameters.push(String::from("&self"));
+                    receiver = String::from("self.");
+                },
+                TypeDesc::Union(types) => {
+                    let union = UnionDesc::new(types)
+                        .map_err(io::Error::other)?;
+                    if param.optional {
+                        parameters.push(format!("{}: Option<{}>", arg_name, union.name));
+                        arguments.push(format!("&{}.map(JsValue::from).unwrap_or(JsValue::UNDEFINED)",
+                                               arg_name));
+                    }
+                    else {
+                        parameters.push(format!("{}: impl Into<{}>", arg_name, union.name));
+                        arguments.push(format!("&JsValue::from(Into::<{}>::into({}))",
+                                               union.name,
+                                               arg_name));
+                    }
                 },
                 _ => {
-                    <&str>::try_from(&rt.type_desc)
+                    let rs_type = Writer::<W>::format_argument(param, class_name)
                         .map_err(|_e| io::Error::new(io::ErrorKind::Other, 
-                                                    format!("Cannot convert return type of {}",
-                                                            function.name)))?
+                                                     format!("Cannot convert parameter {} of {}",
+                                                             arg_name,
+                                                             function.name)))?;
+                    parameters.push(format!("{}: {}", arg_name, rs_type));
+                    arguments.push(arg_name.clone());
                 }
-            };           
-            let rt = match (rt.reference, rt.optional) {
-                (false, false) => format!(" -> {}", rs_type),
-                (false, true) => format!(" -> Option<{}>", rs_type),
-                (true, false) => format!(" -> &{}", rs_type),
-                (true, true) => format!(" -> &Option<{}>", rs_type),
+            }
+        }
+        let call = format!("{}{}({})", receiver, binding.name, arguments.join(", "));
+        if let Some(TypeDesc::Promise(inner_type)) = function.returns.as_ref().map(|rt| &rt.type_desc) {
+            let (rs_type, conversion) = Writer::<W>::format_resolved(inner_type, class_name)
+                .map_err(|_e| io::Error::other(format!("Cannot convert the promised type of {}",
+                                                       function.name)))?;
+            self.write_line(&format!("pub async fn {}({}) -> Result<{}, JsValue> {{",
+                                     function.name,
+                                     parameters.join(", "),
+                                     rs_type))?;
+            self.indentation += 1;
+            self.write_await(&call, conversion)?;
+        }
+        else {
+            self.write_line(&format!("pub fn {}({}){} {{",
+                                     function.name,
+                                     parameters.join(", "),
+                                     Writer::<W>::format_returns(function, class_name)?))?;
+            self.indentation += 1;
+            self.write_line(&call)?;
+        }
+        self.indentation -= 1;
+        self.write_line("}")
+    }
+
+    /* awaits the promise and converts the value that it resolves to */
+    fn write_await(&mut self, promise: &str, conversion: Option<String>) -> io::Result<()> {
+        match conversion {
+            Some(conversion) => {
+                self.write_line(&format!("let value = wasm_bindgen_futures::JsFuture::from({}).await?;", promise))?;
+                self.write_line(&conversion)
+            },
+            None => {
+                self.write_line(&format!("wasm_bindgen_futures::JsFuture::from({}).await?;", promise))?;
+                self.write_line("Ok(())")
+            },
+        }
+    }
+
+    /* the callbacks of load are bound as functions so that they can be the resolve and reject of a promise */
+    fn write_loader_import(&mut self, load: &FunctionDesc, class_name: &str) -> io::Result<()> {
+        let url_type = Writer::<W>::format_loader_url(load)?;
+        self.write_export(&[(String::from("method"), None),
+                            (String::from("js_name"), Some(String::from("load")))])?;
+        self.write_line(&format!("fn load_with_functions(this: &{}, url: {}, on_load: &js_sys::Function, \
+                                  on_progress: &JsValue, on_error: &js_sys::Function);",
+                                 class_name,
+                                 url_type))
+    }
+
+    /* load_async passes the resolve and reject functions of a promise as the callbacks of load */
+    fn write_loader(&mut self, load: &FunctionDesc, class_name: &str) -> io::Result<()> {
+        let url_type = Writer::<W>::format_loader_url(load)?;
+        let loaded_type = match load.arguments.get(2) {
+            Some((_, ParamDesc { type_desc: TypeDesc::Function(parameters, _), .. })) =>
+                parameters.first().map(|(_, type_desc)| type_desc),
+            _ => None,
+        };
+        let (rs_type, conversion) = Writer::<W>::format_resolved(loaded_type.unwrap_or(&TypeDesc::Any), Some(class_name))
+            .map_err(|_e| io::Error::other(format!("Cannot convert the loaded type of {}", class_name)))?;
+        self.write_line(&format!("impl {} {{", class_name))?;
+        self.indentation += 1;
+        self.write_line(&format!("pub async fn load_async(&self, url: {}) -> Result<{}, JsValue> {{",
+                                 url_type,
+                                 rs_type))?;
+        self.indentation += 1;
+        self.write_line("let mut callback = |resolve: js_sys::Function, reject: js_sys::Function| {")?;
+        self.indentation += 1;
+        self.write_line("self.load_with_functions(url, &resolve, &JsValue::UNDEFINED, &reject);")?;
+        self.indentation -= 1;
+        self.write_line("};")?;
+        self.write_await("js_sys::Promise::new(&mut callback)", conversion)?;
+        self.indentation -= 1;
+        self.write_line("}")?;
+        self.indentation -= 1;
+        self.write_line("}")
+    }
+
+    /* the url is borrowed since the promise callback may not move it */
+    fn format_loader_url(load: &FunctionDesc) -> io::Result<String> {
+        let (_, url) = load.arguments.get(1)
+            .ok_or_else(|| io::Error::other("load requires a url"))?;
+        match Writer::<W>::format_type(&url.type_desc, None)? {
+            "String" => Ok(String::from("&str")),
+            rs_type => Ok(format!("&{}", rs_type)),
+        }
+    }
+
+    /* the type of the value that a promise resolves to and how it is converted from JsValue,
+       there is nothing to convert if the promise resolves to nothing */
+    fn format_resolved<'a>(type_desc: &'a TypeDesc, class_name: Option<&'a str>)
+        -> io::Result<(&'a str, Option<String>)> {
+        let checked = |conversion: &str| Some(format!("value.{}().ok_or(value)", conversion));
+        if let Some(number_type) = type_desc.number_array() {
+            return Ok((number_type.2, Some(format!("Ok({}::new(&value).to_vec())", number_type.3))));
+        }
+        match type_desc {
+            TypeDesc::Void | TypeDesc::Undefined => Ok(("()", None)),
+            TypeDesc::Any | TypeDesc::Union(_) => Ok(("JsValue", Some(String::from("Ok(value)")))),
+            TypeDesc::Boolean => Ok(("bool", checked("as_bool"))),
+            TypeDesc::Number => Ok(("f64", checked("as_f64"))),
+            TypeDesc::String => Ok(("String", checked("as_string"))),
+            TypeDesc::Rust(identifier) if type_desc.number_type().is_some() =>
+                Ok((identifier, Some(format!("value.as_f64().map(|number| number as {}).ok_or(value)", identifier)))),
+            type_desc => Writer::<W>::format_type(type_desc, class_name)
+                .map(|rs_type| (rs_type, Some(String::from("Ok(wasm_bindgen::JsCast::unchecked_into(value))")))),
+        }
+    }
+
+    pub fn write_union(&mut self, union: &UnionDesc) -> io::Result<()> {
+        let variants = union.variants
+            .iter()
+            .map(|(variant, type_desc)| UnionDesc::variant_type(type_desc)
+                .map(|rs_type| (variant.as_str(), type_desc, rs_type))
+                .map_err(io::Error::other))
+            .collect::<io::Result<Vec<_>>>()?;
+        self.write_line("#[derive(Clone, Debug)]")?;
+        self.write_line(&format!("pub enum {} {{", union.name))?;
+        self.indentation += 1;
+        for (variant, _, rs_type) in &variants {
+            self.write_line(&format!("{}({}),", variant, rs_type))?;
+        }
+        self.indentation -= 1;
+        self.write_line("}")?;
+        for (variant, type_desc, rs_type) in &variants {
+            /* conversions from the variant type and its common borrowed or literal forms */
+            let mut conversions = vec![(String::from(*rs_type), String::from("value"))];
+            match type_desc {
+                TypeDesc::Rust(_) if type_desc.number_type().is_some() => {},
+                TypeDesc::Any | TypeDesc::Class(_) | TypeDesc::Generic(_, _) |
+                TypeDesc::Builtin(_) | TypeDesc::Rust(_) =>
+                    conversions.push((format!("&{}", rs_type), String::from("Clone::clone(value)"))),
+                TypeDesc::String =>
+                    conversions.push((String::from("&str"), String::from("value.to_owned()"))),
+                TypeDesc::Number => {
+                    conversions.push((String::from("i32"), String::from("value.into()")));
+                    conversions.push((String::from("u32"), String::from("value.into()")));
+                },
+                TypeDesc::Array(_) => conversions.extend(type_desc
+                    .number_array()
+                    .map(|number_type| (String::from(number_type.1), String::from("value.to_vec()")))),
+                _ => {}
+            }
+            for (from_type, conversion) in conversions {
+                self.write_line(&format!("impl From<{}> for {} {{", from_type, union.name))?;
+                self.indentation += 1;
+                self.write_line(&format!("fn from(value: {}) -> Self {{", from_type))?;
+                self.indentation += 1;
+                self.write_line(&format!("{}::{}({})", union.name, variant, conversion))?;
+                self.indentation -= 1;
+                self.write_line("}")?;
+                self.indentation -= 1;
+                self.write_line("}")?;
+            }
+        }
+        self.write_line(&format!("impl From<{}> for JsValue {{", union.name))?;
+        self.indentation += 1;
+        self.write_line(&format!("fn from(value: {}) -> Self {{", union.name))?;
+        self.indentation += 1;
+        self.write_line("match value {")?;
+        self.indentation += 1;
+        for (variant, type_desc, _) in &variants {
+            let conversion = match type_desc.number_array() {
+                Some(number_type) => format!("{}::from(&value[..]).into()", number_type.3),
+                None => String::from("value.into()"),
             };
-            fn_str.push_str(&rt);
+            self.write_line(&format!("{}::{}(value) => {},", union.name, variant, conversion))?;
         }
-        fn_str.push(';');
-        self.write_line(&fn_str)
+        self.indentation -= 1;
+        self.write_line("}")?;
+        self.indentation -= 1;
+        self.write_line("}")?;
+        self.indentation -= 1;
+        self.write_line("}")
     }
 
-    pub fn write_class(&mut self, class: ClassDesc) -> io::Result<()> {
+    pub fn write_class(&mut self, class: &ClassDesc) -> io::Result<()> {
         self.write_export(&class.attributes)?;
+        /* imported types implement neither Clone nor Debug, which the enums of the unions derive */
+        self.write_line("#[derive(Clone, Debug)]")?;
         let class_decl = format!("pub type {};", class.name);
         self.write_line(&class_decl)?;
         /* write class methods */
         for function in &class.methods {
-            self.write_function(function, Some(&class.name))?;
+            if function.has_wrapper() {
+                self.write_function(&function.lower(), Some(&class.name))?;
+            }
+            else {
+                self.write_function(function, Some(&class.name))?;
+            }
+        }
+        if let Some(load) = class.loader() {
+            self.write_loader_import(load, &class.name)?;
         }
         Ok(())
     }
 
+    pub fn write_static(&mut self, value: &StaticDesc) -> io::Result<()> {
+        let rs_type = StaticDesc::static_type(&value.type_desc)
+            .map_err(|_e| io::Error::other(format!("Cannot convert the type of {}", value.name)))?;
+        /* the value is read from JavaScript on the first access of each thread */
+        let mut attributes = vec![(String::from("thread_local_v2"), None)];
+        attributes.extend(value.attributes.iter().cloned());
+        self.write_export(&attributes)?;
+        self.write_line(&format!("pub static {}: {};", value.name, rs_type))
+    }
+
+    fn write_functions(&mut self, functions: &[FunctionDesc]) -> io::Result<()> {
+        for function in functions {
+            if function.has_wrapper() {
+                self.write_function(&function.lower(), None)?;
+            }
+            else {
+                self.write_function(function, None)?;
+            }
+        }
+        Ok(())
+    }
+
+    /* writes the declarations that are imported from the JavaScript module in a single extern block */
     pub fn write_module(&mut self, module: ModuleDesc) -> io::Result<()> {
-        self.write_export(&module.attributes)?;
+        for enumeration in &module.enums {
+            self.write_enum(enumeration)?;
+        }
+        for enumeration in &module.string_enums {
+            self.write_string_enum(enumeration)?;
+        }
+        /* namespaces that are not nested share the module of their file */
+        let (namespaces, nested_namespaces) : (Vec<NamespaceDesc>, Vec<NamespaceDesc>) = module.namespaces
+            .into_iter()
+            .partition(|namespace| namespace.module.is_none());
+        if !module.classes.is_empty() ||
+           !module.functions.is_empty() ||
+           !module.statics.is_empty() ||
+           !namespaces.is_empty() {
+            self.write_export(&module.attributes)?;
+            self.write_line("extern \"C\" {")?;
+            self.set_indentation(1);
+            for class in &module.classes {
+                self.write_class(class)?;
+            }
+            self.write_functions(&module.functions)?;
+            for value in &module.statics {
+                self.write_static(value)?;
+            }
+            for namespace in &namespaces {
+                self.write_namespace_functions(namespace)?;
+            }
+            self.set_indentation(0);
+            self.write_line("}")?;
+        }
+        if !module.interfaces.is_empty() || !module.intersections.is_empty() {
+            self.write_line("#[wasm_bindgen]")?;
+            self.write_line("extern \"C\" {")?;
+            self.set_indentation(1);
+            for interface in module.interfaces.iter().chain(&module.intersections) {
+                self.write_class(interface)?;
+            }
+            self.set_indentation(0);
+            self.write_line("}")?;
+        }
+        for class in module.classes.iter().chain(&module.interfaces).chain(&module.intersections) {
+            self.write_wrappers(&class.methods, Some(&class.name))?;
+            if let Some(load) = class.loader() {
+                self.write_loader(load, &class.name)?;
+            }
+        }
+        self.write_wrappers(&module.functions, None)?;
+        for namespace in &namespaces {
+            self.write_namespace_wrappers(namespace)?;
+        }
+        for interface in &module.interfaces {
+            self.write_interface(interface)?;
+        }
+        for namespace in nested_namespaces {
+            self.write_namespace(&module.attributes, namespace)?;
+        }
+        Ok(())
+    }
+
+    /* writes the enums for the union arguments and the functions that convert them */
+    fn write_wrappers(&mut self, functions: &[FunctionDesc], class_name: Option<&str>) -> io::Result<()> {
+        let wrappers : Vec<&FunctionDesc> = functions
+            .iter()
+            .filter(|function| function.has_wrapper())
+            .collect();
+        if wrappers.is_empty() {
+            return Ok(());
+        }
+        let mut unions : Vec<UnionDesc> = Vec::new();
+        for (_, param) in wrappers.iter().flat_map(|function| &function.arguments) {
+            if let TypeDesc::Union(types) = &param.type_desc {
+                let union = UnionDesc::new(types)
+                    .map_err(io::Error::other)?;
+                if !self.unions.contains(&union.name) &&
+                   !unions.iter().any(|other| other.name == union.name) {
+                    unions.push(union);
+                }
+            }
+        }
+        for union in unions {
+            self.write_union(&union)?;
+            self.unions.push(union.name);
+        }
+        if let Some(class_name) = class_name {
+            self.write_line(&format!("impl {} {{", class_name))?;
+            self.indentation += 1;
+        }
+        for function in wrappers {
+            self.write_wrapper(function, class_name)?;
+        }
+        if class_name.is_some() {
+            self.indentation -= 1;
+            self.write_line("}")?;
+        }
+        Ok(())
+    }
+
+    /* the variables of a namespace are accessed through the static accessors of an extern type
+       for the namespace object, its functions are written as free functions */
+    fn write_namespace_functions(&mut self, namespace: &NamespaceDesc) -> io::Result<()> {
+        if namespace.functions.iter().any(|function| function.is_static) {
+            self.write_line(&format!("pub type {};", namespace.name))?;
+        }
+        for function in &namespace.functions {
+            let class_name = Some(namespace.name.as_str()).filter(|_| function.is_static);
+            if function.has_wrapper() {
+                self.write_function(&function.lower(), class_name)?;
+            }
+            else {
+                self.write_function(function, class_name)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_namespace_wrappers(&mut self, namespace: &NamespaceDesc) -> io::Result<()> {
+        let (accessors, functions) : (Vec<FunctionDesc>, Vec<FunctionDesc>) = namespace.functions
+            .iter()
+            .cloned()
+            .partition(|function| function.is_static);
+        self.write_wrappers(&accessors, Some(&namespace.name))?;
+        self.write_wrappers(&functions, None)
+    }
+
+    /* namespaces are written as free functions, in a nested module unless they name their file */
+    pub fn write_namespace(&mut self,
+                           attributes: &[(String, Option<String>)],
+                           namespace: NamespaceDesc) -> io::Result<()> {
+        if let Some(module) = &namespace.module {
+            self.write_line(&format!("pub mod {} {{", module))?;
+            self.indentation += 1;
+            self.write_line("use super::*;")?;
+        }
+        self.write_export(attributes)?;
         self.write_line("extern \"C\" {")?;
+        self.indentation += 1;
+        self.write_namespace_functions(&namespace)?;
+        self.indentation -= 1;
+        self.write_line("}")?;
+        /* the enums of a nested module are not visible to the rest of the file */
+        let unions = self.unions.len();
+        self.write_namespace_wrappers(&namespace)?;
+        if namespace.module.is_some() {
+            self.unions.truncate(unions);
+            self.indentation -= 1;
+            self.write_line("}")?;
+        }
+        Ok(())
+    }
+
+    pub fn write_enum(&mut self, enumeration: &EnumDesc) -> io::Result<()> {
+        /* variants that share a value with a previous variant are written as aliases */
+        let mut variants : Vec<&(String, u32)> = Vec::with_capacity(enumeration.variants.len());
+        let mut aliases = Vec::new();
+        for variant in &enumeration.variants {
+            match variants.iter().find(|other| other.1 == variant.1) {
+                Some(other) => aliases.push((&variant.0, &other.0)),
+                None => variants.push(variant),
+            }
+        }
+        self.write_line("#[wasm_bindgen]")?;
+        self.write_line("#[allow(non_camel_case_types)]")?;
+        self.write_line("#[derive(Clone, Copy, Debug, PartialEq, Eq)]")?;
+        self.write_line("#[repr(u32)]")?;
+        self.write_line(&format!("pub enum {} {{", enumeration.name))?;
+        self.indentation += 1;
+        for (variant, value) in &variants {
+            self.write_line(&format!("{} = {},", variant, value))?;
+        }
+        self.indentation -= 1;
+        self.write_line("}")?;
+        if !aliases.is_empty() {
+            self.write_line(&format!("impl {} {{", enumeration.name))?;
+            self.indentation += 1;
+            for (alias, variant) in aliases {
+                self.write_line("#[allow(non_upper_case_globals)]")?;
+                self.write_line(&format!("pub const {}: {} = {}::{};",
+                                         alias,
+                                         enumeration.name,
+                                         enumeration.name,
+                                         variant))?;
+            }
+            self.indentation -= 1;
+            self.write_line("}")?;
+        }
+        /* conversions from and to the underlying values, wasm_bindgen converts into JsValue */
+        let name = &enumeration.name;
+        self.write_line(&format!("impl From<{}> for u32 {{", name))?;
+        self.indentation += 1;
+        self.write_line(&format!("fn from(value: {}) -> Self {{", name))?;
+        self.indentation += 1;
+        self.write_line("value as u32")?;
+        self.indentation -= 1;
+        self.write_line("}")?;
+        self.indentation -= 1;
+        self.write_line("}")?;
+        self.write_line(&format!("impl std::convert::TryFrom<u32> for {} {{", name))?;
+        self.indentation += 1;
+        self.write_line("type Error = u32;")?;
+        self.write_line("fn try_from(value: u32) -> Result<Self, Self::Error> {")?;
+        self.indentation += 1;
+        self.write_line("match value {")?;
+        self.indentation += 1;
+        for (variant, value) in &variants {
+            self.write_line(&format!("{} => Ok({}::{}),", value, name, variant))?;
+        }
+        self.write_line("_ => Err(value),")?;
+        self.indentation -= 1;
+        self.write_line("}")?;
+        self.indentation -= 1;
+        self.write_line("}")?;
+        self.indentation -= 1;
+        self.write_line("}")?;
+        self.write_line(&format!("impl std::convert::TryFrom<JsValue> for {} {{", name))?;
+        self.indentation += 1;
+        self.write_line("type Error = JsValue;")?;
+        self.write_line("fn try_from(value: JsValue) -> Result<Self, Self::Error> {")?;
+        self.indentation += 1;
+        self.write_line("match value.as_f64() {")?;
+        self.indentation += 1;
+        self.write_line("Some(number) if number >= 0.0 && number <= f64::from(u32::MAX) && number.fract() == 0.0 =>")?;
+        self.indentation += 1;
+        self.write_line("<Self as std::convert::TryFrom<u32>>::try_from(number as u32).map_err(|_| value),")?;
+        self.indentation -= 1;
+        self.write_line("_ => Err(value),")?;
+        self.indentation -= 1;
+        self.write_line("}")?;
+        self.indentation -= 1;
+        self.write_line("}")?;
+        self.indentation -= 1;
+        self.write_line("}")
+    }
+
+    /* interfaces only exist in TypeScript and are created as plain JavaScript objects */
+    fn write_interface(&mut self, interface: &ClassDesc) -> io::Result<()> {
+        self.write_line(&format!("impl {} {{", interface.name))?;
+        self.set_indentation(1);
+        self.write_line(&format!("pub fn new() -> {} {{", interface.name))?;
+        self.set_indentation(2);
+        self.write_line("wasm_bindgen::JsCast::unchecked_into(js_sys::Object::new())")?;
         self.set_indentation(1);
-        self.write_class(module.class)?;
+        self.write_line("}")?;
         self.set_indentation(0);
+        self.write_line("}")
+    }
+
+    /* wasm-bindgen converts the variants from and to their strings, e.g., with to_str */
+    pub fn write_string_enum(&mut self, enumeration: &StringEnumDesc) -> io::Result<()> {
+        self.write_line("#[wasm_bindgen]")?;
+        self.write_line("#[derive(Clone, Copy, Debug, PartialEq, Eq)]")?;
+        self.write_line(&format!("pub enum {} {{", enumeration.name))?;
+        self.indentation += 1;
+        for (variant, value) in &enumeration.variants {
+            self.write_line(&format!("{} = {:?},", variant, value))?;
+        }
+        self.indentation -= 1;
+        self.write_line("}")
+    }
+
+    /* a trait for reading and writing the values of objects that are indexed by strings */
+    pub fn write_records(&mut self) -> io::Result<()> {
+        self.write_line("use wasm_bindgen::{JsCast, JsValue, UnwrapThrowExt};\n")?;
+        self.write_line("pub trait Record: AsRef<JsValue> {")?;
+        self.indentation += 1;
+        self.write_line("/// Returns the value of the key if it is defined.")?;
+        self.write_line("fn get_value(&self, key: &str) -> Option<JsValue> {")?;
+        self.indentation += 1;
+        self.write_line("js_sys::Reflect::get(self.as_ref(), &JsValue::from_str(key))")?;
+        self.indentation += 1;
+        self.write_line(".ok()")?;
+        self.write_line(".filter(|value| !value.is_undefined())")?;
+        self.indentation -= 2;
+        self.write_line("}")?;
+        self.write_line("/// Returns the value of the key if it is defined and has the type `T`.")?;
+        self.write_line("fn get_entry<T: JsCast>(&self, key: &str) -> Option<T> {")?;
+        self.indentation += 1;
+        self.write_line("self.get_value(key).and_then(|value| value.dyn_into().ok())")?;
+        self.indentation -= 1;
+        self.write_line("}")?;
+        /* primitives are not JsCast, they are read through the conversions of JsValue */
+        for (name, rs_type, conversion) in &[("number", "f64", "as_f64"),
+                                             ("string", "String", "as_string"),
+                                             ("bool", "bool", "as_bool")] {
+            self.write_line(&format!("/// Returns the value of the key if it is defined and is a {}.", name))?;
+            self.write_line(&format!("fn get_{}(&self, key: &str) -> Option<{}> {{", name, rs_type))?;
+            self.indentation += 1;
+            self.write_line(&format!("self.get_value(key).and_then(|value| value.{}())", conversion))?;
+            self.indentation -= 1;
+            self.write_line("}")?;
+        }
+        self.write_line("/// Sets the value of the key.")?;
+        self.write_line("fn set_entry<T: AsRef<JsValue>>(&self, key: &str, value: &T) {")?;
+        self.indentation += 1;
+        self.write_line("js_sys::Reflect::set(self.as_ref(), &JsValue::from_str(key), value.as_ref())")?;
+        self.indentation += 1;
+        self.write_line(".expect_throw(\"records are objects\");")?;
+        self.indentation -= 2;
+        self.write_line("}")?;
+        self.write_line("/// Sets the key to a value that converts into a `JsValue`, e.g., a number, string or bool.")?;
+        self.write_line("fn set_value<T: Into<JsValue>>(&self, key: &str, value: T) {")?;
+        self.indentation += 1;
+        self.write_line("self.set_entry(key, &value.into())")?;
+        self.indentation -= 1;
+        self.write_line("}")?;
+        self.write_line("/// Removes the key and its value.")?;
+        self.write_line("fn delete_entry(&self, key: &str) {")?;
+        self.indentation += 1;
+        self.write_line("js_sys::Reflect::delete_property(self.as_ref().unchecked_ref::<js_sys::Object>(), &JsValue::from_str(key))")?;
+        self.indentation += 1;
+        self.write_line(".expect_throw(\"records are objects\");")?;
+        self.indentation -= 2;
+        self.write_line("}")?;
+        self.write_line("/// Returns the keys of the record.")?;
+        self.write_line("fn keys(&self) -> Vec<String> {")?;
+        self.indentation += 1;
+        self.write_line("js_sys::Object::keys(self.as_ref().unchecked_ref::<js_sys::Object>())")?;
+        self.indentation += 1;
+        self.write_line(".iter()")?;
+        self.write_line(".filter_map(|key| key.as_string())")?;
+        self.write_line(".collect()")?;
+        self.indentation -= 2;
+        self.write_line("}")?;
+        self.indentation -= 1;
         self.write_line("}")?;
+        self.write_line("")?;
+        self.write_line("impl Record for js_sys::Object {}")
+    }
+
+    /* a trait for creating the typed arrays from slices with the same element type */
+    pub fn write_typed_arrays(&mut self) -> io::Result<()> {
+        self.write_line("pub trait TypedArray: Sized {")?;
+        self.indentation += 1;
+        self.write_line("type Element;")?;
+        self.write_line("/// Copies the slice into a new typed array.")?;
+        self.write_line("fn from_slice(slice: &[Self::Element]) -> Self;")?;
+        self.write_line("/// Views the slice in the wasm memory without copying it.")?;
+        self.write_line("///")?;
+        self.write_line("/// # Safety")?;
+        self.write_line("///")?;
+        self.write_line("/// The view is invalidated when the wasm memory grows, so it must")?;
+        self.write_line("/// not be used after any allocations.")?;
+        self.write_line("unsafe fn view(slice: &[Self::Element]) -> Self;")?;
+        self.indentation -= 1;
+        self.write_line("}")?;
+        for (_, rs_type, element) in BUILTIN_TYPES {
+            let element = match element {
+                Some(element) => element,
+                None => continue,
+            };
+            self.write_line(&format!("impl TypedArray for {} {{", rs_type))?;
+            self.indentation += 1;
+            self.write_line(&format!("type Element = {};", element))?;
+            self.write_line("fn from_slice(slice: &[Self::Element]) -> Self {")?;
+            self.indentation += 1;
+            self.write_line(&format!("{}::from(slice)", rs_type))?;
+            self.indentation -= 1;
+            self.write_line("}")?;
+            self.write_line("unsafe fn view(slice: &[Self::Element]) -> Self {")?;
+            self.indentation += 1;
+            self.write_line(&format!("{}::view(slice)", rs_type))?;
+            self.indentation -= 1;
+            self.write_line("}")?;
+            self.indentation -= 1;
+            self.write_line("}")?;
+        }
         Ok(())
     }
 